@@ -1,18 +1,332 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
-#[command(version, about, long_about = None)]
+#[command(
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Schema generation options, used when no subcommand is given
+    #[command(flatten)]
+    pub generate: Option<GenerateArgs>,
+    #[command(flatten)]
+    pub log: LogArgs,
+}
+
+// Logging options shared by every command; logs always go to stderr.
+// Not a doc comment: clap would show it as the program description.
+#[derive(Args)]
+pub struct LogArgs {
+    #[arg(
+        short,
+        long,
+        global = true,
+        conflicts_with = "verbose",
+        help = "에러만 출력"
+    )]
+    pub quiet: bool,
+    #[arg(
+        short,
+        long,
+        global = true,
+        help = "설정, 로드한 시트, 생성한 스키마 등 상세 정보도 출력"
+    )]
+    pub verbose: bool,
+    #[arg(
+        long,
+        value_enum,
+        global = true,
+        default_value_t = LogFormat::Text,
+        help = "로그 출력 형식"
+    )]
+    pub log_format: LogFormat,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// 스키마를 로드한 뒤 모든 데이터 행을 검증
+    Validate(ValidateArgs),
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
     #[arg(short, long, help = "입력 파일 경로")]
     pub input_file_path: PathBuf,
     #[arg(short, long, help = "출력 파일 경로")]
     pub output_file_path: PathBuf,
+    #[arg(
+        long = "format",
+        value_name = "FORMAT[=PATH]",
+        value_delimiter = ',',
+        default_value = "ts",
+        value_parser = parse_format_output,
+        help = "스키마 출력 형식 (ts, sql, json-schema, effect, zod, rust, proto, graphql). 여러 번 또는 쉼표로 지정하며, FORMAT=PATH로 형식별 출력 경로 지정 (생략 시 출력 파일 경로의 확장자만 바꿈)"
+    )]
+    pub formats: Vec<(String, Option<PathBuf>)>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = SqlDialectFormat::Postgres,
+        help = "sql-ddl 출력의 SQL 방언"
+    )]
+    pub sql_dialect: SqlDialectFormat,
+    #[arg(short, long, help = "데이터 출력 디렉터리 경로 (시트별 JSON 파일)")]
+    pub data_dir_path: Option<PathBuf>,
+    #[arg(long, help = "SQLite 데이터베이스 출력 경로 (스키마별 테이블)")]
+    pub sqlite_path: Option<PathBuf>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Int64Format::Bigint,
+        help = "64비트 정수(int64, uint64) 표현 방식"
+    )]
+    pub int64: Int64Format,
+    #[arg(long, help = "알 수 없는 타입을 문자열로 대체하지 않고 에러로 처리")]
+    pub strict: bool,
+    #[arg(
+        long,
+        help = "Price{Mid}, Price{Low}처럼 기본 이름이 같은 한정자 컬럼을 중첩 객체로 묶음"
+    )]
+    pub nest_qualifiers: bool,
+    #[arg(
+        long,
+        help = "같은 바이트를 공유하는 인접한 bit&XX 컬럼을 flags 객체로 묶음"
+    )]
+    pub group_flags: bool,
+    #[arg(
+        long = "inline",
+        value_name = "SHEET=FIELDS",
+        value_parser = parse_inline_fields,
+        help = "데이터 추출 시 SHEET 참조를 지정한 필드만 가진 객체로 펼침 (예: ItemUICategory=id,name,icon)"
+    )]
+    pub inline_fields: Vec<(String, Vec<String>)>,
+    #[arg(
+        long,
+        default_value_t = 1,
+        help = "--inline 참조를 따라가는 최대 단계 수"
+    )]
+    pub inline_depth: usize,
+    #[arg(long, help = "커스텀 타입 참조 값이 대상 시트의 키로 존재하는지 검사")]
+    pub check_references: bool,
+    #[arg(
+        long,
+        requires = "check_references",
+        help = "참조 검사 시 0을 '참조 없음'으로 허용하지 않음"
+    )]
+    pub no_zero_sentinel: bool,
+    #[arg(
+        long,
+        help = "첫 에러에서 멈추지 않고 참조 그래프 전체를 처리한 뒤 누락 파일, 헤더, 타입 에러를 한꺼번에 보고"
+    )]
+    pub keep_going: bool,
+    #[arg(
+        long,
+        value_name = "PATH|-",
+        help = "읽고 쓴 파일(크기, SHA-256), 생성한 스키마, 경고, 에러를 담은 JSON 실행 보고서 경로 (-는 stdout)"
+    )]
+    pub report: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    #[arg(short, long, help = "입력 파일 경로")]
+    pub input_file_path: PathBuf,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ReportFormat::Human,
+        help = "검증 결과 출력 형식"
+    )]
+    pub format: ReportFormat,
+    #[arg(long, help = "알 수 없는 타입을 문자열로 대체하지 않고 에러로 처리")]
+    pub strict: bool,
+    #[arg(long, help = "커스텀 타입 참조 값이 대상 시트의 키로 존재하는지 검사")]
+    pub check_references: bool,
+    #[arg(
+        long,
+        requires = "check_references",
+        help = "참조 검사 시 0을 '참조 없음'으로 허용하지 않음"
+    )]
+    pub no_zero_sentinel: bool,
+    #[arg(
+        long,
+        help = "첫 에러에서 멈추지 않고 참조 그래프 전체를 처리한 뒤 누락 파일, 헤더, 타입 에러를 한꺼번에 보고"
+    )]
+    pub keep_going: bool,
+}
+
+/// Parse `format` or `format=path` into the format name and its optional output path
+fn parse_format_output(value: &str) -> Result<(String, Option<PathBuf>), String> {
+    let (format, path) = match value.split_once('=') {
+        Some((format, path)) if !path.trim().is_empty() => {
+            (format, Some(PathBuf::from(path.trim())))
+        }
+        Some(_) => return Err(format!("expected FORMAT=PATH, found {:?}", value)),
+        None => (value, None),
+    };
+
+    if format.trim().is_empty() {
+        return Err(format!("expected FORMAT[=PATH], found {:?}", value));
+    }
+
+    Ok((format.trim().to_string(), path))
+}
+
+/// Parse `Sheet=field1,field2` into the sheet name and its whitelisted fields
+fn parse_inline_fields(value: &str) -> Result<(String, Vec<String>), String> {
+    let (sheet, fields) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected SHEET=FIELDS, found {:?}", value))?;
+    let fields: Vec<String> = fields
+        .split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    if sheet.trim().is_empty() || fields.is_empty() {
+        return Err(format!("expected SHEET=FIELDS, found {:?}", value));
+    }
+
+    Ok((sheet.trim().to_string(), fields))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SqlDialectFormat {
+    /// PostgreSQL (순환 참조는 ALTER TABLE로 추가)
+    Postgres,
+    /// SQLite (순환 참조는 DEFERRABLE로 선언)
+    Sqlite,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Int64Format {
+    /// 10진수 문자열, BigInt로 디코딩 (JSON에는 bigint 리터럴이 없음)
+    Bigint,
+    /// 10진수 문자열
+    String,
+    /// number (2^53 초과 값은 정밀도 손실)
+    Number,
+    /// 16비트 4개 튜플 [number, number, number, number]
+    Quad,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// 사람이 읽기 쉬운 텍스트
+    Human,
+    /// 파이프라인용 JSON
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// 사람이 읽기 쉬운 텍스트
+    Text,
+    /// 한 줄에 하나씩 JSON 객체
+    Json,
 }
 
 #[test]
 fn verify_cli() {
     use clap::CommandFactory;
     Cli::command().debug_assert();
+
+    // Flattened argument groups must not leak their docs into the program description
+    let help = Cli::command().render_help().to_string();
+    assert!(
+        help.starts_with("Usage: "),
+        "unexpected help header: {}",
+        help
+    );
+}
+
+#[test]
+fn verify_parse_inline_fields() {
+    assert_eq!(
+        parse_inline_fields("ItemUICategory=id, name,icon"),
+        Ok((
+            "ItemUICategory".to_string(),
+            vec!["id".to_string(), "name".to_string(), "icon".to_string()]
+        ))
+    );
+    assert!(parse_inline_fields("ItemUICategory").is_err());
+    assert!(parse_inline_fields("ItemUICategory=").is_err());
+}
+
+#[test]
+fn verify_format_outputs() {
+    let cli = Cli::try_parse_from([
+        "gleaner",
+        "-i",
+        "Item.csv",
+        "-o",
+        "out/schemas.ts",
+        "--format",
+        "ts,sql=db/schema.sql",
+        "--format",
+        "sql-ddl",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        cli.generate.unwrap().formats,
+        vec![
+            ("ts".to_string(), None),
+            ("sql".to_string(), Some(PathBuf::from("db/schema.sql"))),
+            ("sql-ddl".to_string(), None),
+        ]
+    );
+    assert!(parse_format_output("sql=").is_err());
+    assert!(parse_format_output("=out.sql").is_err());
+}
+
+#[test]
+fn verify_validate_subcommand() {
+    let cli =
+        Cli::try_parse_from(["gleaner", "validate", "-i", "Item.csv", "--format", "json"]).unwrap();
+    assert!(cli.generate.is_none());
+    match cli.command {
+        Some(Command::Validate(args)) => {
+            assert_eq!(args.input_file_path, PathBuf::from("Item.csv"));
+            assert_eq!(args.format, ReportFormat::Json);
+        }
+        None => panic!("Expected validate subcommand"),
+    }
+
+    let cli = Cli::try_parse_from(["gleaner", "-i", "Item.csv", "-o", "schemas.ts"]).unwrap();
+    assert!(cli.command.is_none());
+    assert!(cli.generate.is_some());
+
+    assert!(Cli::try_parse_from(["gleaner", "-i", "Item.csv"]).is_err());
+}
+
+#[test]
+fn verify_log_args() {
+    let cli = Cli::try_parse_from([
+        "gleaner",
+        "-i",
+        "Item.csv",
+        "-o",
+        "schemas.ts",
+        "--quiet",
+        "--log-format",
+        "json",
+    ])
+    .unwrap();
+    assert!(cli.log.quiet);
+    assert_eq!(cli.log.log_format, LogFormat::Json);
+
+    // Logging options are accepted after a subcommand too
+    let cli = Cli::try_parse_from(["gleaner", "validate", "-i", "Item.csv", "-v"]).unwrap();
+    assert!(cli.log.verbose);
+
+    assert!(Cli::try_parse_from(["gleaner", "-i", "Item.csv", "-o", "s.ts", "-q", "-v"]).is_err());
 }
@@ -1,6 +1,7 @@
 /// Basic type names that should not be treated as custom types
 pub const BASIC_TYPES: &[&str] = &[
-    "str", "int32", "uint32", "int16", "uint16", "byte", "sbyte", "float", "bool",
+    "str", "int32", "uint32", "int64", "uint64", "int16", "uint16", "byte", "sbyte", "float",
+    "bool",
 ];
 
 /// Patterns that indicate a type is likely a custom type
@@ -11,6 +12,9 @@ pub const CUSTOM_TYPE_PATTERNS: &[&str] = &[
 /// Special type names that have unique processing rules
 pub const SPECIAL_TYPES: &[&str] = &["Image", "Row", "Key", "Color"];
 
+/// Key conventionally used by reference columns to mean "no reference"
+pub const ZERO_SENTINEL: &str = "0";
+
 /// Header indicators for CSV row detection
 pub const FIELD_NAMES_HEADER: &str = "key";
 pub const FIELD_DESCRIPTIONS_HEADER: &str = "#";
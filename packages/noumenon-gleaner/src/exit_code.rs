@@ -0,0 +1,91 @@
+use noumenon_gleaner::SchemaError;
+
+/// Process exit codes, a stable part of the CLI (see docs/schema-generation-process.md).
+///
+/// `1` is never used, so a generic failure of a wrapper is not mistaken for one of these.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitCode {
+    Success = 0,
+    /// Invalid command-line options (clap also exits with 2)
+    Usage = 2,
+    /// A referenced CSV file does not exist
+    MissingFile = 3,
+    /// A CSV file does not follow the sheet format
+    MalformedInput = 4,
+    /// Data rows do not match their schema
+    InvalidData = 5,
+    /// Reading or writing a file failed
+    Io = 6,
+}
+
+impl ExitCode {
+    pub fn from_error(error: &SchemaError) -> Self {
+        match error {
+            SchemaError::FileNotFound { .. } => ExitCode::MissingFile,
+            SchemaError::CsvError(error) if matches!(error.kind(), csv::ErrorKind::Io(_)) => {
+                ExitCode::Io
+            }
+            SchemaError::CsvError(_)
+            | SchemaError::InvalidFormat { .. }
+            | SchemaError::InvalidBitValue { .. }
+            | SchemaError::UnknownType { .. }
+            | SchemaError::BitMaskConflict { .. }
+            | SchemaError::MissingCsvHeader { .. }
+            | SchemaError::DuplicateCsvHeader { .. } => ExitCode::MalformedInput,
+            SchemaError::InvalidValue { .. } | SchemaError::ColumnCountMismatch { .. } => {
+                ExitCode::InvalidData
+            }
+            SchemaError::IoError(_) | SchemaError::SqliteError(_) => ExitCode::Io,
+            SchemaError::InvalidInlineField { .. }
+            | SchemaError::InlineFormatConflict { .. }
+            | SchemaError::UnknownFormat { .. }
+            | SchemaError::OutputPathConflict { .. } => ExitCode::Usage,
+            // Several errors from --keep-going exit with the code of the first one
+            SchemaError::Multiple { errors } => errors
+                .first()
+                .map_or(ExitCode::MalformedInput, ExitCode::from_error),
+        }
+    }
+
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+
+    pub fn exit(self) -> ! {
+        std::process::exit(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_error() {
+        let missing = SchemaError::FileNotFound {
+            path: "ItemLevel.csv".to_string(),
+            source_file: "Item.csv".to_string(),
+        };
+        assert_eq!(ExitCode::from_error(&missing).code(), 3);
+
+        let header = SchemaError::DuplicateCsvHeader {
+            header: "key".to_string(),
+            path: "Item.csv".to_string(),
+        };
+        assert_eq!(ExitCode::from_error(&header).code(), 4);
+
+        let io = SchemaError::IoError(std::io::Error::other("disk full"));
+        assert_eq!(ExitCode::from_error(&io).code(), 6);
+
+        let format = SchemaError::UnknownFormat {
+            format: "xml".to_string(),
+            available: "ts".to_string(),
+        };
+        assert_eq!(ExitCode::from_error(&format).code(), 2);
+
+        let multiple = SchemaError::Multiple {
+            errors: vec![missing, header],
+        };
+        assert_eq!(ExitCode::from_error(&multiple).code(), 3);
+    }
+}
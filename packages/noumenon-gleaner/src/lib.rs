@@ -0,0 +1,51 @@
+//! Schema and data extraction for Noumenon game data sheets.
+//!
+//! Each sheet is a CSV file with three header rows (field indices, field descriptions
+//! and field types) followed by data rows. [`SchemaBuilder`] reads a sheet together
+//! with every sheet it references and collects a [`Schema`] and the raw data rows for
+//! each of them. The resulting [`SchemaMap`] feeds the [`SchemaGenerator`]
+//! implementations (TypeScript, SQL, JSON Schema, ...), [`DataExporter`] turns rows into
+//! JSON, and [`DataValidator`] checks rows against their schemas.
+//!
+//! ```no_run
+//! use noumenon_gleaner::{
+//!     DataExporter, GeneratorRegistry, Int64Representation, SchemaBuilder, SchemaError,
+//!     SqlDialect,
+//! };
+//!
+//! // Load every sheet in a directory
+//! let mut builder = SchemaBuilder::new();
+//! builder.build_schemas_from_dir("fixtures")?;
+//! let schemas = builder.get_all_schemas();
+//!
+//! // Iterate the rows of one sheet as JSON objects
+//! let exporter = DataExporter::new();
+//! for row in exporter.rows(&schemas["Item"], &builder.get_all_rows()["Item"]) {
+//!     println!("{}", row?["name"]);
+//! }
+//!
+//! // Run a generator by its `--format` name
+//! let registry = GeneratorRegistry::new()
+//!     .with_builtin_generators(Int64Representation::default(), SqlDialect::default());
+//! let typescript = registry.get("ts")?.generate(schemas);
+//! # let _ = typescript;
+//! # Ok::<(), SchemaError>(())
+//! ```
+//!
+//! The `cli` feature (on by default) builds the `noumenon-gleaner` binary; library
+//! users can disable default features to drop `clap` and `sha2`. The `sqlite` feature (also on
+//! by default) provides `SqliteExporter`.
+
+mod constants;
+mod schema;
+
+#[cfg(feature = "sqlite")]
+pub use schema::SqliteExporter;
+pub use schema::{
+    DanglingReference, DataExporter, DataValidator, Diagnostic, EffectSchemaGenerator, Field,
+    FieldType, GeneratorRegistry, GraphqlGenerator, Int64Representation, IssueKind,
+    JsonSchemaGenerator, ProtoGenerator, ReferenceEdge, ReferenceReport, ReferenceValidator,
+    RowMap, RustGenerator, Schema, SchemaBuilder, SchemaError, SchemaGenerator, SchemaMap,
+    Severity, SqlDdlGenerator, SqlDialect, TypeCoercion, TypeScriptGenerator, ValidationIssue,
+    ValidationReport, Value, ZodGenerator, analyze_missing_files,
+};
@@ -1,95 +1,453 @@
 mod cli;
-mod constants;
-mod schema;
+mod exit_code;
+mod report;
 
 use clap::Parser;
-use cli::Cli;
-use schema::{SchemaBuilder, SchemaError, TypeScriptGenerator, analyze_missing_files};
+use cli::{Cli, Command, GenerateArgs, Int64Format, ReportFormat, SqlDialectFormat, ValidateArgs};
+use exit_code::ExitCode;
+use noumenon_gleaner::{
+    DataExporter, DataValidator, Diagnostic, GeneratorRegistry, Int64Representation,
+    ReferenceReport, ReferenceValidator, Schema, SchemaBuilder, SchemaError, SchemaMap, Severity,
+    SqlDialect, SqliteExporter, TypeScriptGenerator, ValidationReport, analyze_missing_files,
+};
+use report::{Level, Reporter, RunReport};
+use serde_json::{Map, Value as JsonValue, json};
+use std::collections::HashSet;
+use std::path::Path;
 
 fn main() {
     let cli = Cli::parse();
+    let reporter = Reporter::new(&cli.log);
 
-    print_configuration(&cli);
+    match (cli.command, cli.generate) {
+        (Some(Command::Validate(args)), _) => run_validate(&args, &reporter),
+        (None, Some(args)) => run_generate(&args, &reporter),
+        (None, None) => unreachable!("clap requires generation arguments without a subcommand"),
+    }
+}
+
+fn run_generate(cli: &GenerateArgs, reporter: &Reporter) {
+    report_configuration(cli, reporter);
 
-    match build_schemas(&cli) {
+    let mut run = RunReport::new(&cli.input_file_path);
+    let result = build_schemas(cli, reporter, &mut run);
+    match &result {
         Ok(main_schema_name) => {
-            print_success(&main_schema_name);
+            reporter.info(
+                &format!("Successfully built schema: {}", main_schema_name),
+                JsonValue::Null,
+            );
         }
-        Err(e) => {
-            print_error(&e);
-            if let SchemaError::FileNotFound { .. } = e {
-                suggest_missing_files(&cli);
-            }
-            if let SchemaError::InvalidFormat { .. } = e {
-                print_csv_format_guidance();
+        Err(e) => report_failure(e, &cli.input_file_path, reporter),
+    }
+
+    let exit_code = match &result {
+        Ok(_) => ExitCode::Success,
+        Err(e) => ExitCode::from_error(e),
+    };
+
+    // Written last so the report includes the errors above
+    if let Some(report_path) = &cli.report
+        && let Err(e) = run.save(exit_code, reporter, report_path)
+    {
+        report_error(&e, reporter);
+        ExitCode::from_error(&e).exit();
+    }
+
+    exit_code.exit();
+}
+
+fn run_validate(args: &ValidateArgs, reporter: &Reporter) {
+    let mut schema_builder = SchemaBuilder::new()
+        .with_strict(args.strict)
+        .with_keep_going(args.keep_going);
+    let result = schema_builder.build_schema_from_file(&args.input_file_path);
+    report_diagnostics(schema_builder.diagnostics(), reporter);
+    if let Err(e) = result {
+        match args.format {
+            ReportFormat::Human => report_failure(&e, &args.input_file_path, reporter),
+            ReportFormat::Json => {
+                println!("{:#}", ValidationReport::from_build_error(&e).to_json())
             }
-            std::process::exit(1);
         }
+        ExitCode::from_error(&e).exit();
+    }
+
+    let mut validator = DataValidator::new();
+    if args.check_references {
+        validator = validator
+            .with_references(ReferenceValidator::new().with_zero_sentinel(!args.no_zero_sentinel));
+    }
+    let report = validator.validate(
+        schema_builder.get_all_schemas(),
+        schema_builder.get_all_rows(),
+    );
+
+    // The report is the requested output, so it goes to stdout
+    match args.format {
+        ReportFormat::Human => print!("{}", report),
+        ReportFormat::Json => println!("{:#}", report.to_json()),
+    }
+
+    if !report.is_valid() {
+        ExitCode::InvalidData.exit();
     }
 }
 
-fn print_configuration(cli: &Cli) {
-    println!("input_file_path: {:?}", cli.input_file_path);
-    println!("output_file_path: {:?}", cli.output_file_path);
+fn report_configuration(cli: &GenerateArgs, reporter: &Reporter) {
+    let formats: Vec<String> = cli
+        .formats
+        .iter()
+        .map(|(format, path)| match path {
+            Some(path) => format!("{}={}", format, path.display()),
+            None => format.clone(),
+        })
+        .collect();
+    let inline_fields: Vec<String> = cli
+        .inline_fields
+        .iter()
+        .map(|(sheet, fields)| format!("{}={}", sheet, fields.join(",")))
+        .collect();
+
+    reporter.debug(
+        "Configuration",
+        json!({
+            "input_file_path": cli.input_file_path.display().to_string(),
+            "output_file_path": cli.output_file_path.display().to_string(),
+            "formats": formats,
+            "sql_dialect": format!("{:?}", cli.sql_dialect),
+            "data_dir_path": cli.data_dir_path.as_ref().map(|path| path.display().to_string()),
+            "sqlite_path": cli.sqlite_path.as_ref().map(|path| path.display().to_string()),
+            "int64": format!("{:?}", cli.int64),
+            "strict": cli.strict,
+            "nest_qualifiers": cli.nest_qualifiers,
+            "group_flags": cli.group_flags,
+            "inline_fields": inline_fields,
+            "inline_depth": cli.inline_depth,
+            "check_references": cli.check_references,
+            "no_zero_sentinel": cli.no_zero_sentinel,
+            "keep_going": cli.keep_going,
+            "report": cli.report.as_ref().map(|path| path.display().to_string()),
+        }),
+    );
 }
 
-fn build_schemas(cli: &Cli) -> Result<String, SchemaError> {
-    let mut schema_builder = SchemaBuilder::new();
-    let main_schema_name = schema_builder.build_and_print_schemas(&cli.input_file_path)?;
+fn build_schemas(
+    cli: &GenerateArgs,
+    reporter: &Reporter,
+    run: &mut RunReport,
+) -> Result<String, SchemaError> {
+    // Resolve output formats first so a typo fails before any CSV is read
+    let registry = generator_registry(cli);
+    let outputs = registry.resolve_outputs(&cli.formats, &cli.output_file_path)?;
+
+    // Inlined data files only match formats that can describe the inlined references
+    let inlines_data =
+        cli.data_dir_path.is_some() && !cli.inline_fields.is_empty() && cli.inline_depth > 0;
+    if inlines_data
+        && let Some((generator, _)) = outputs
+            .iter()
+            .find(|(generator, _)| !generator.supports_inlined_data())
+    {
+        return Err(SchemaError::InlineFormatConflict {
+            format: generator.name().to_string(),
+        });
+    }
 
-    // Generate TypeScript interfaces after successful schema building
-    let generator = TypeScriptGenerator::new();
-    generator.generate_and_save(schema_builder.get_all_schemas(), &cli.output_file_path)?;
-    println!(
-        "TypeScript interfaces generated: {}",
-        cli.output_file_path.display()
+    let mut schema_builder = SchemaBuilder::new()
+        .with_strict(cli.strict)
+        .with_nested_qualifiers(cli.nest_qualifiers)
+        .with_grouped_flags(cli.group_flags)
+        .with_keep_going(cli.keep_going);
+    let result = schema_builder.build_schema_from_file(&cli.input_file_path);
+    report_diagnostics(schema_builder.diagnostics(), reporter);
+    for diagnostic in schema_builder.diagnostics() {
+        if let Diagnostic::SheetLoaded { path, .. } = diagnostic {
+            run.add_file_read(path);
+        }
+    }
+    run.add_schemas(
+        schema_builder.get_all_schemas(),
+        schema_builder.get_all_rows(),
     );
+    let main_schema_name = result?;
+    report_schemas(schema_builder.get_all_schemas(), reporter);
+
+    if cli.check_references {
+        let report = ReferenceValidator::new()
+            .with_zero_sentinel(!cli.no_zero_sentinel)
+            .validate(
+                schema_builder.get_all_schemas(),
+                schema_builder.get_all_rows(),
+            );
+        report_references(&report, reporter);
+    }
+
+    // Generate every requested schema output after successful schema building
+    for (generator, path) in outputs {
+        generator.generate_and_save(schema_builder.get_all_schemas(), &path)?;
+        run.add_file_written(&path);
+        reporter.info(
+            &format!("Generated {} output: {}", generator.name(), path.display()),
+            json!({ "format": generator.name(), "path": path.display().to_string() }),
+        );
+    }
+
+    // Extract data rows only when an output directory is requested
+    if let Some(data_dir_path) = &cli.data_dir_path {
+        let mut exporter = DataExporter::new()
+            .with_int64_representation(int64_representation(cli.int64))
+            .with_inline_depth(cli.inline_depth);
+        for (sheet, fields) in &cli.inline_fields {
+            exporter = exporter.with_inline_fields(sheet, fields.clone());
+        }
+        let written = exporter.export_and_save(
+            schema_builder.get_all_schemas(),
+            schema_builder.get_all_rows(),
+            data_dir_path,
+        )?;
+        for path in &written {
+            run.add_file_written(path);
+        }
+        reporter.info(
+            &format!(
+                "Data files extracted: {} ({} sheets)",
+                data_dir_path.display(),
+                written.len()
+            ),
+            json!({ "path": data_dir_path.display().to_string(), "sheets": written.len() }),
+        );
+    }
+
+    if let Some(sqlite_path) = &cli.sqlite_path {
+        SqliteExporter::new().export_and_save(
+            schema_builder.get_all_schemas(),
+            schema_builder.get_all_rows(),
+            sqlite_path,
+        )?;
+        run.add_file_written(sqlite_path);
+        reporter.info(
+            &format!("SQLite database written: {}", sqlite_path.display()),
+            json!({ "path": sqlite_path.display().to_string() }),
+        );
+    }
 
     Ok(main_schema_name)
 }
 
-fn print_success(schema_name: &str) {
-    println!("Successfully built schema: {}", schema_name);
+/// All output formats selectable with `--format`, configured from the CLI options
+fn generator_registry(cli: &GenerateArgs) -> GeneratorRegistry {
+    // TypeScript interfaces describe the data files, including inlined references
+    let mut typescript = TypeScriptGenerator::new()
+        .with_int64_representation(int64_representation(cli.int64))
+        .with_inline_depth(cli.inline_depth);
+    if cli.data_dir_path.is_some() {
+        for (sheet, fields) in &cli.inline_fields {
+            typescript = typescript.with_inline_fields(sheet, fields.clone());
+        }
+    }
+
+    GeneratorRegistry::new()
+        .with_builtin_generators(
+            int64_representation(cli.int64),
+            sql_dialect(cli.sql_dialect),
+        )
+        .with_generator(typescript)
+}
+
+/// Builder diagnostics: loaded sheets are detail, coercions and renames are warnings
+fn report_diagnostics(diagnostics: &[Diagnostic], reporter: &Reporter) {
+    for diagnostic in diagnostics {
+        let level = match diagnostic.severity() {
+            Severity::Info => Level::Debug,
+            Severity::Warning => Level::Warn,
+        };
+        let (message, fields) = match diagnostic {
+            Diagnostic::SheetLoaded { sheet, path, rows } => (
+                diagnostic.to_string(),
+                json!({ "sheet": sheet, "path": path, "rows": rows }),
+            ),
+            Diagnostic::TypeCoerced(coercion) => (
+                format!("{} (use --strict to reject)", diagnostic),
+                json!({
+                    "kind": "unknown-type",
+                    "token": coercion.token,
+                    "column": coercion.column,
+                    "path": coercion.path,
+                }),
+            ),
+            Diagnostic::FieldRenamed {
+                path,
+                column,
+                name,
+                renamed,
+            } => (
+                diagnostic.to_string(),
+                json!({
+                    "kind": "renamed-field",
+                    "path": path,
+                    "column": column,
+                    "name": name,
+                    "renamed": renamed,
+                }),
+            ),
+        };
+        reporter.log(level, &message, fields);
+    }
 }
 
-fn print_error(error: &SchemaError) {
-    eprintln!("Error: {}", error);
+fn report_schemas(schemas: &SchemaMap, reporter: &Reporter) {
+    let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+    sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
 
-    if let SchemaError::FileNotFound { path, source_file } = error {
-        eprintln!("\nRequired file not found: {}", path);
-        eprintln!("Referenced from: {}", source_file);
-        eprintln!(
-            "Make sure all referenced CSV files exist in the same directory as the input file."
+    for schema in sorted_schemas {
+        let fields: Map<String, JsonValue> = schema
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), format!("{:?}", field.field_type).into()))
+            .collect();
+        reporter.debug(
+            &format!("Schema: {}", schema.name),
+            JsonValue::Object(fields),
         );
     }
 }
 
-fn suggest_missing_files(cli: &Cli) {
-    match analyze_missing_files(&cli.input_file_path) {
-        Ok(missing_files) if !missing_files.is_empty() => {
-            eprintln!("\nSuggested files to create:");
-            for file in missing_files {
-                eprintln!("  - {}.csv", file);
+fn report_references(report: &ReferenceReport, reporter: &Reporter) {
+    for edge in &report.edges {
+        reporter.info(
+            &format!(
+                "Reference check {}.{} -> {}: {} checked, {} sentinel(s), {} dangling",
+                edge.sheet, edge.field, edge.target, edge.checked, edge.sentinels, edge.dangling
+            ),
+            json!({
+                "sheet": edge.sheet,
+                "field": edge.field,
+                "target": edge.target,
+                "checked": edge.checked,
+                "sentinels": edge.sentinels,
+                "dangling": edge.dangling,
+            }),
+        );
+    }
+
+    for reference in &report.dangling {
+        reporter.warn(
+            &format!(
+                "Dangling reference {} (row: {}, column: {}) -> {} key {:?} not found",
+                reference.sheet,
+                reference.row_key,
+                reference.column,
+                reference.target,
+                reference.missing_key
+            ),
+            json!({
+                "kind": "dangling-reference",
+                "sheet": reference.sheet,
+                "row_key": reference.row_key,
+                "column": reference.column,
+                "target": reference.target,
+                "missing_key": reference.missing_key,
+            }),
+        );
+    }
+}
+
+fn int64_representation(format: Int64Format) -> Int64Representation {
+    match format {
+        Int64Format::Bigint => Int64Representation::BigInt,
+        Int64Format::String => Int64Representation::String,
+        Int64Format::Number => Int64Representation::Number,
+        Int64Format::Quad => Int64Representation::Quad,
+    }
+}
+
+fn sql_dialect(format: SqlDialectFormat) -> SqlDialect {
+    match format {
+        SqlDialectFormat::Postgres => SqlDialect::Postgres,
+        SqlDialectFormat::Sqlite => SqlDialect::Sqlite,
+    }
+}
+
+/// Report a failed build together with hints for fixing it
+fn report_failure(error: &SchemaError, input_file_path: &Path, reporter: &Reporter) {
+    report_error(error, reporter);
+    suggest_missing_files(error, input_file_path, reporter);
+
+    let errors = match error {
+        SchemaError::Multiple { errors } => errors.as_slice(),
+        error => std::slice::from_ref(error),
+    };
+    if errors
+        .iter()
+        .any(|error| matches!(error, SchemaError::InvalidFormat { .. }))
+    {
+        report_csv_format_guidance(reporter);
+    }
+}
+
+/// Report an error, or each collected error of a `--keep-going` build
+fn report_error(error: &SchemaError, reporter: &Reporter) {
+    match error {
+        SchemaError::Multiple { errors } => {
+            for error in errors {
+                report_error(error, reporter);
             }
         }
-        Err(e) => eprintln!("Could not analyze input file for suggestions: {}", e),
-        _ => {} // No missing files
+        _ => reporter.error(&error.to_string(), error.to_json()),
     }
 }
 
-fn print_csv_format_guidance() {
-    eprintln!("");
-    eprintln!("CSV format example:");
-    eprintln!("----------------------");
-    eprintln!("key,0,1,2");
-    eprintln!("#,Name,Level,IsActive");
-    eprintln!("int32,str,byte,bool");
-    eprintln!("1,\"Test\",10,true");
-    eprintln!("");
-    eprintln!("- The first row must start with 'key' and contains field indices/names.");
-    eprintln!("- The second row must start with '#' and contains field descriptions.");
-    eprintln!("- The third row contains field types (e.g., str, int32, etc.).");
-    eprintln!("- Data rows start from the fourth row.");
-    eprintln!("");
-    eprintln!("See docs/schema-generation-process.md for detailed format rules.");
+fn suggest_missing_files(error: &SchemaError, input_file_path: &Path, reporter: &Reporter) {
+    let mut files: Vec<String> = match error {
+        // A --keep-going build knows every missing file
+        SchemaError::Multiple { errors } => errors
+            .iter()
+            .filter_map(|error| match error {
+                SchemaError::FileNotFound { path, .. } => Path::new(path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect(),
+        // Otherwise only the first one is known, so guess the rest from the input file
+        SchemaError::FileNotFound { .. } => match analyze_missing_files(input_file_path) {
+            Ok(missing_files) => missing_files
+                .iter()
+                .map(|file| format!("{}.csv", file))
+                .collect(),
+            Err(e) => {
+                reporter.warn(
+                    &format!("Could not analyze input file for suggestions: {}", e),
+                    JsonValue::Null,
+                );
+                return;
+            }
+        },
+        _ => return,
+    };
+
+    // Keep the first mention of each file
+    let mut seen = HashSet::new();
+    files.retain(|file| seen.insert(file.clone()));
+
+    if !files.is_empty() {
+        reporter.info(
+            &format!(
+                "Suggested files to create next to the input file: {}",
+                files.join(", ")
+            ),
+            json!({ "files": files }),
+        );
+    }
+}
+
+fn report_csv_format_guidance(reporter: &Reporter) {
+    reporter.info(
+        "CSV files need a 'key' row of field indices, a '#' row of descriptions and a row of \
+         field types before the data rows (see docs/schema-generation-process.md)",
+        json!({
+            "example": ["key,0,1,2", "#,Name,Level,IsActive", "int32,str,byte,bool", "1,\"Test\",10,true"],
+        }),
+    );
 }
@@ -0,0 +1,371 @@
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use noumenon_gleaner::{RowMap, Schema, SchemaError, SchemaMap};
+use serde_json::{Map, Value as JsonValue, json};
+use sha2::{Digest, Sha256};
+
+use crate::cli::{LogArgs, LogFormat};
+use crate::exit_code::ExitCode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    fn as_str(self) -> &'static str {
+        match self {
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+        }
+    }
+}
+
+/// Writes progress, warnings and errors to stderr, so stdout only carries requested output.
+///
+/// Warnings and errors are also recorded, whatever the verbosity, for the run report.
+pub struct Reporter {
+    min_level: Level,
+    format: LogFormat,
+    recorded: RefCell<Vec<(Level, JsonValue)>>,
+}
+
+impl Reporter {
+    pub fn new(args: &LogArgs) -> Self {
+        let min_level = if args.quiet {
+            Level::Error
+        } else if args.verbose {
+            Level::Debug
+        } else {
+            Level::Info
+        };
+
+        Self {
+            min_level,
+            format: args.log_format,
+            recorded: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn enabled(&self, level: Level) -> bool {
+        level >= self.min_level
+    }
+
+    /// Log an event; `fields` is a JSON object of structured details
+    pub fn log(&self, level: Level, message: &str, fields: JsonValue) {
+        let fields = into_fields(fields);
+
+        if level >= Level::Warn {
+            let mut event = Map::new();
+            event.insert("message".to_string(), message.into());
+            event.extend(fields.clone());
+            self.recorded
+                .borrow_mut()
+                .push((level, JsonValue::Object(event)));
+        }
+
+        if self.enabled(level) {
+            eprintln!("{}", self.render(level, message, fields));
+        }
+    }
+
+    /// Recorded events of one level, each a message with its fields
+    pub fn recorded(&self, level: Level) -> Vec<JsonValue> {
+        self.recorded
+            .borrow()
+            .iter()
+            .filter(|(recorded_level, _)| *recorded_level == level)
+            .map(|(_, event)| event.clone())
+            .collect()
+    }
+
+    pub fn debug(&self, message: &str, fields: JsonValue) {
+        self.log(Level::Debug, message, fields);
+    }
+
+    pub fn info(&self, message: &str, fields: JsonValue) {
+        self.log(Level::Info, message, fields);
+    }
+
+    pub fn warn(&self, message: &str, fields: JsonValue) {
+        self.log(Level::Warn, message, fields);
+    }
+
+    pub fn error(&self, message: &str, fields: JsonValue) {
+        self.log(Level::Error, message, fields);
+    }
+
+    fn render(&self, level: Level, message: &str, fields: Map<String, JsonValue>) -> String {
+        match self.format {
+            LogFormat::Text => render_text(level, message, &fields, self.enabled(Level::Debug)),
+            LogFormat::Json => {
+                let mut event = Map::new();
+                event.insert("level".to_string(), level.as_str().into());
+                event.insert("message".to_string(), message.into());
+                event.extend(fields);
+                JsonValue::Object(event).to_string()
+            }
+        }
+    }
+}
+
+fn into_fields(fields: JsonValue) -> Map<String, JsonValue> {
+    match fields {
+        JsonValue::Object(fields) => fields,
+        JsonValue::Null => Map::new(),
+        other => Map::from_iter([("value".to_string(), other)]),
+    }
+}
+
+/// Machine-readable summary of a generation run, written with `--report`
+pub struct RunReport {
+    input_file_path: PathBuf,
+    schemas: Vec<JsonValue>,
+    files_read: Vec<PathBuf>,
+    files_written: Vec<PathBuf>,
+}
+
+impl RunReport {
+    pub fn new(input_file_path: &Path) -> Self {
+        Self {
+            input_file_path: input_file_path.to_path_buf(),
+            schemas: Vec::new(),
+            files_read: Vec::new(),
+            files_written: Vec::new(),
+        }
+    }
+
+    /// Record every built schema with its field names and row count
+    pub fn add_schemas(&mut self, schemas: &SchemaMap, rows: &RowMap) {
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        self.schemas = sorted_schemas
+            .into_iter()
+            .map(|schema| {
+                let fields: Vec<&str> = schema
+                    .fields
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect();
+                json!({
+                    "name": schema.name,
+                    "fields": fields,
+                    "rows": rows.get(&schema.name).map_or(0, Vec::len),
+                })
+            })
+            .collect();
+    }
+
+    pub fn add_file_read(&mut self, path: impl Into<PathBuf>) {
+        self.files_read.push(path.into());
+    }
+
+    pub fn add_file_written(&mut self, path: impl Into<PathBuf>) {
+        self.files_written.push(path.into());
+    }
+
+    /// Build the report, hashing every recorded file as it is now on disk
+    pub fn to_json(
+        &self,
+        exit_code: ExitCode,
+        reporter: &Reporter,
+    ) -> Result<JsonValue, SchemaError> {
+        let files_read = self
+            .files_read
+            .iter()
+            .map(|path| file_entry(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let files_written = self
+            .files_written
+            .iter()
+            .map(|path| file_entry(path))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(json!({
+            "success": exit_code == ExitCode::Success,
+            "exit_code": exit_code.code(),
+            "input_file_path": self.input_file_path.display().to_string(),
+            "schemas": self.schemas,
+            "files_read": files_read,
+            "files_written": files_written,
+            "warnings": reporter.recorded(Level::Warn),
+            "errors": reporter.recorded(Level::Error),
+        }))
+    }
+
+    /// Write the report to `path`, or to stdout when `path` is `-`
+    pub fn save(
+        &self,
+        exit_code: ExitCode,
+        reporter: &Reporter,
+        path: &Path,
+    ) -> Result<(), SchemaError> {
+        let report = format!("{:#}\n", self.to_json(exit_code, reporter)?);
+
+        if path == Path::new("-") {
+            print!("{}", report);
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, report)?;
+        }
+        Ok(())
+    }
+}
+
+/// Path, size and SHA-256 of a file
+fn file_entry(path: &Path) -> Result<JsonValue, SchemaError> {
+    let bytes = fs::read(path)?;
+    let sha256: String = Sha256::digest(&bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    Ok(json!({
+        "path": path.display().to_string(),
+        "bytes": bytes.len(),
+        "sha256": sha256,
+    }))
+}
+
+/// Message on the first line; in verbose mode followed by one indented line per field
+fn render_text(
+    level: Level,
+    message: &str,
+    fields: &Map<String, JsonValue>,
+    with_fields: bool,
+) -> String {
+    let prefix = match level {
+        Level::Warn => "Warning: ",
+        Level::Error => "Error: ",
+        Level::Debug | Level::Info => "",
+    };
+    let mut output = format!("{}{}", prefix, message);
+
+    if !with_fields {
+        return output;
+    }
+
+    for (key, value) in fields {
+        match value {
+            JsonValue::Array(items) => {
+                output.push_str(&format!("\n  {}:", key));
+                for item in items {
+                    output.push_str(&format!("\n    - {}", text_value(item)));
+                }
+            }
+            value => output.push_str(&format!("\n  {}: {}", key, text_value(value))),
+        }
+    }
+
+    output
+}
+
+fn text_value(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn reporter(quiet: bool, verbose: bool, log_format: LogFormat) -> Reporter {
+        Reporter::new(&LogArgs {
+            quiet,
+            verbose,
+            log_format,
+        })
+    }
+
+    #[test]
+    fn test_levels_follow_verbosity() {
+        let quiet = reporter(true, false, LogFormat::Text);
+        assert!(!quiet.enabled(Level::Warn));
+        assert!(quiet.enabled(Level::Error));
+
+        let normal = reporter(false, false, LogFormat::Text);
+        assert!(!normal.enabled(Level::Debug));
+        assert!(normal.enabled(Level::Info));
+
+        let verbose = reporter(false, true, LogFormat::Text);
+        assert!(verbose.enabled(Level::Debug));
+    }
+
+    #[test]
+    fn test_render_text() {
+        let fields = json!({ "path": "Item.csv", "column": 2, "files": ["A.csv", "B.csv"] });
+
+        let normal = reporter(false, false, LogFormat::Text);
+        assert_eq!(
+            normal.render(Level::Warn, "Unknown type", into_fields(fields.clone())),
+            "Warning: Unknown type"
+        );
+
+        let verbose = reporter(false, true, LogFormat::Text);
+        assert_eq!(
+            verbose.render(Level::Warn, "Unknown type", into_fields(fields)),
+            "Warning: Unknown type\n  path: Item.csv\n  column: 2\n  files:\n    - A.csv\n    - B.csv"
+        );
+        assert_eq!(verbose.render(Level::Info, "Done", Map::new()), "Done");
+    }
+
+    #[test]
+    fn test_render_json() {
+        let reporter = reporter(false, false, LogFormat::Json);
+        let line = reporter.render(
+            Level::Error,
+            "Failed",
+            into_fields(json!({ "path": "Item.csv" })),
+        );
+
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&line).unwrap(),
+            json!({ "level": "error", "message": "Failed", "path": "Item.csv" })
+        );
+    }
+
+    #[test]
+    fn test_run_report() {
+        let temp_dir = TempDir::new().unwrap();
+        let csv_path = temp_dir.path().join("Item.csv");
+        fs::write(&csv_path, "abc").unwrap();
+
+        let reporter = reporter(true, false, LogFormat::Text);
+        reporter.warn(
+            "Unknown type",
+            json!({ "kind": "unknown-type", "column": 2 }),
+        );
+        reporter.info("Done", JsonValue::Null);
+
+        let mut run = RunReport::new(&csv_path);
+        run.add_file_read(&csv_path);
+        let report = run.to_json(ExitCode::Success, &reporter).unwrap();
+
+        assert_eq!(
+            report["files_read"][0],
+            json!({
+                "path": csv_path.display().to_string(),
+                "bytes": 3,
+                "sha256": "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            })
+        );
+        assert_eq!(
+            report["warnings"],
+            json!([{ "message": "Unknown type", "kind": "unknown-type", "column": 2 }])
+        );
+        assert_eq!(report["errors"], json!([]));
+    }
+}
@@ -1,45 +1,115 @@
-use std::collections::HashSet;
-use std::fs::File;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
 use std::path::Path;
 
 use super::error::SchemaError;
-use super::types::{Field, FieldType, Schema, SchemaMap};
+use super::types::{Diagnostic, Field, FieldType, RowMap, Schema, SchemaMap, TypeCoercion};
 use super::utils::{
     extract_schema_name_from_path, is_likely_custom_type, is_special_type, parse_bit_value,
+    parse_indexed_name, parse_qualified_name, split_qualified_words,
 };
 use crate::constants::{BASIC_TYPES, FIELD_DESCRIPTIONS_HEADER, FIELD_NAMES_HEADER};
 
+/// A single CSV column with its resolved type
+struct Column {
+    index: usize,
+    name: String,
+    description: String,
+    field_type: FieldType,
+}
+
+/// An indexed `Name[n]` run collapsed into one array field
+#[derive(Clone)]
+struct ArrayGroup {
+    base: String,
+    element_type: FieldType,
+    length: usize,
+    columns: Vec<usize>, // CSV columns in read order (element by element)
+}
+
+impl ArrayGroup {
+    fn into_grouped_field(self) -> GroupedField {
+        GroupedField {
+            base: self.base,
+            field_type: FieldType::Array(Box::new(self.element_type), self.length),
+            columns: self.columns,
+            synthesized: false,
+        }
+    }
+}
+
+/// Several CSV columns collapsed into one field (arrays, nested qualifiers, flags)
+struct GroupedField {
+    base: String, // Description the field name is derived from
+    field_type: FieldType,
+    columns: Vec<usize>,
+    synthesized: bool, // Name made up by the builder rather than taken from the CSV
+}
+
 pub struct SchemaBuilder {
     schemas: SchemaMap,
+    rows: RowMap,
     processing_stack: HashSet<String>, // For circular dependency detection
+    strict: bool,
+    nest_qualifiers: bool,
+    group_flags: bool,
+    diagnostics: Vec<Diagnostic>,
+    keep_going: bool,
+    errors: Vec<SchemaError>, // Collected in keep-going mode
+    failed: HashSet<String>,  // Sheets that failed to build in keep-going mode
 }
 
 impl SchemaBuilder {
     pub fn new() -> Self {
         Self {
             schemas: SchemaMap::new(),
+            rows: RowMap::new(),
             processing_stack: HashSet::new(),
+            strict: false,
+            nest_qualifiers: false,
+            group_flags: false,
+            diagnostics: Vec::new(),
+            keep_going: false,
+            errors: Vec::new(),
+            failed: HashSet::new(),
         }
     }
 
-    /// High-level function to build schemas and print results
-    pub fn build_and_print_schemas<P: AsRef<Path>>(
-        &mut self,
-        file_path: P,
-    ) -> Result<String, SchemaError> {
-        let main_schema_name = self.build_schema_from_file(file_path)?;
+    /// Reject unknown type tokens instead of coercing them to string
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Nest `Base{Qualifier}` columns sharing a base name into one object field
+    pub fn with_nested_qualifiers(mut self, nest_qualifiers: bool) -> Self {
+        self.nest_qualifiers = nest_qualifiers;
+        self
+    }
 
-        println!("\n=== Generated Schemas ===");
-        self.print_schemas();
+    /// Group adjacent bit columns that share a byte into one flags object field
+    pub fn with_grouped_flags(mut self, group_flags: bool) -> Self {
+        self.group_flags = group_flags;
+        self
+    }
 
-        Ok(main_schema_name)
+    /// Keep building the rest of the reference graph after an error, and report every
+    /// error together at the end (as `SchemaError::Multiple` when there are several)
+    pub fn with_keep_going(mut self, keep_going: bool) -> Self {
+        self.keep_going = keep_going;
+        self
     }
 
+    /// Build the schema of a sheet and every sheet it references, returning its name
     pub fn build_schema_from_file<P: AsRef<Path>>(
         &mut self,
         file_path: P,
     ) -> Result<String, SchemaError> {
-        let path = file_path.as_ref();
+        let result = self.build_file(file_path.as_ref());
+        self.take_collected_errors(result)
+    }
+
+    fn build_file(&mut self, path: &Path) -> Result<String, SchemaError> {
         let schema_name =
             extract_schema_name_from_path(path).ok_or_else(|| SchemaError::InvalidFormat {
                 reason: "Cannot extract schema name from file path".to_string(),
@@ -48,19 +118,53 @@ impl SchemaBuilder {
         self.build_schema_recursive(&schema_name, path.parent().unwrap_or(Path::new(".")), path)
     }
 
+    /// Build the schemas of every `.csv` sheet in a directory, returning their names in order
+    pub fn build_schemas_from_dir<P: AsRef<Path>>(
+        &mut self,
+        dir_path: P,
+    ) -> Result<Vec<String>, SchemaError> {
+        let mut csv_paths = Vec::new();
+        for entry in fs::read_dir(dir_path.as_ref())? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "csv") {
+                csv_paths.push(path);
+            }
+        }
+        // Sort paths for a consistent build order
+        csv_paths.sort();
+
+        let result = csv_paths.iter().map(|path| self.build_file(path)).collect();
+        self.take_collected_errors(result)
+    }
+
+    /// Turn the errors collected in keep-going mode into the build's result
+    fn take_collected_errors<T>(
+        &mut self,
+        result: Result<T, SchemaError>,
+    ) -> Result<T, SchemaError> {
+        let value = result?;
+        let mut errors = std::mem::take(&mut self.errors);
+
+        match errors.len() {
+            0 => Ok(value),
+            1 => Err(errors.remove(0)),
+            _ => Err(SchemaError::Multiple { errors }),
+        }
+    }
+
     fn build_schema_recursive(
         &mut self,
         schema_name: &str,
         base_dir: &Path,
         source_file: &Path,
     ) -> Result<String, SchemaError> {
-        // Return if already processed
-        if self.schemas.contains_key(schema_name) {
+        // Return if already processed (or already failed in keep-going mode)
+        if self.schemas.contains_key(schema_name) || self.failed.contains(schema_name) {
             return Ok(schema_name.to_string());
         }
 
         // Check for circular dependency
-        if self.processing_stack.contains(&schema_name.to_string()) {
+        if self.processing_stack.contains(schema_name) {
             // If we're already processing this schema, just return the name
             // This allows circular references to be resolved later
             return Ok(schema_name.to_string());
@@ -71,22 +175,37 @@ impl SchemaBuilder {
 
         let csv_path = base_dir.join(format!("{}.csv", schema_name));
 
-        if !csv_path.exists() {
-            // Remove from processing stack before returning error
-            self.processing_stack.remove(&schema_name.to_string());
-            return Err(SchemaError::FileNotFound {
+        let result = if csv_path.exists() {
+            self.parse_csv_file(&csv_path, schema_name, base_dir, source_file)
+        } else {
+            Err(SchemaError::FileNotFound {
                 path: csv_path.to_string_lossy().to_string(),
                 source_file: source_file.to_string_lossy().to_string(),
-            });
-        }
-
-        let schema = self.parse_csv_file(&csv_path, schema_name, base_dir, source_file)?;
-        self.schemas.insert(schema_name.to_string(), schema);
+            })
+        };
 
         // Remove from processing stack
-        self.processing_stack.remove(&schema_name.to_string());
-
-        Ok(schema_name.to_string())
+        self.processing_stack.remove(schema_name);
+
+        match result {
+            Ok(schema) => {
+                self.schemas.insert(schema_name.to_string(), schema);
+                self.diagnostics.push(Diagnostic::SheetLoaded {
+                    sheet: schema_name.to_string(),
+                    path: csv_path.display().to_string(),
+                    rows: self.rows[schema_name].len(),
+                });
+                Ok(schema_name.to_string())
+            }
+            // Record the error and let the referencing sheet carry on with its other columns
+            Err(error) if self.keep_going => {
+                self.rows.remove(schema_name);
+                self.failed.insert(schema_name.to_string());
+                self.errors.push(error);
+                Ok(schema_name.to_string())
+            }
+            Err(error) => Err(error),
+        }
     }
 
     fn parse_csv_file(
@@ -97,16 +216,20 @@ impl SchemaBuilder {
         _source_file: &Path,
     ) -> Result<Schema, SchemaError> {
         let file = File::open(csv_path)?;
+        // Data rows with a wrong column count are kept and reported by validation/export
         let mut rdr = csv::ReaderBuilder::new()
             .has_headers(false)
+            .flexible(true)
             .from_reader(file);
 
         let records: Vec<_> = rdr.records().collect::<Result<_, _>>()?;
 
         if records.len() < 3 {
             return Err(SchemaError::InvalidFormat {
-                reason: "CSV file must have at least 3 rows (names, descriptions, types)"
-                    .to_string(),
+                reason: format!(
+                    "{} must have at least 3 rows (names, descriptions, types)",
+                    csv_path.display()
+                ),
             });
         }
 
@@ -115,6 +238,12 @@ impl SchemaBuilder {
         let field_descriptions_row = Self::find_field_descriptions_row(&records, csv_path)?;
         let field_types_row = Self::find_field_types_row(&records, csv_path)?;
 
+        // Every record after the type row is a data row
+        self.rows.insert(
+            schema_name.to_string(),
+            records[field_types_row + 1..].to_vec(),
+        );
+
         let field_names = &records[field_names_row];
         let field_descriptions = &records[field_descriptions_row];
         let field_types = &records[field_types_row];
@@ -124,44 +253,87 @@ impl SchemaBuilder {
                 reason: "Field names and types count mismatch".to_string(),
             });
         }
+        if field_names.len() != field_descriptions.len() {
+            return Err(SchemaError::InvalidFormat {
+                reason: "Field names and descriptions count mismatch".to_string(),
+            });
+        }
 
-        let mut fields = Vec::new();
-        let mut used_names = std::collections::HashSet::new();
-
-        for ((name, description), type_str) in field_names
+        // Resolve every column's type up front so indexed runs can be grouped by type
+        let mut columns = Vec::new();
+        for (index, ((name, description), type_str)) in field_names
             .iter()
             .zip(field_descriptions.iter())
             .zip(field_types.iter())
+            .enumerate()
         {
             // Special handling for "Key" description - override type to Key
             let field_type = if description == "Key" {
                 FieldType::Key
             } else {
-                self.parse_field_type(type_str, base_dir, csv_path)?
+                self.parse_field_type(type_str, index, base_dir, csv_path)?
             };
 
-            // Determine the best field name to use
-            let mut field_name = if description.starts_with('#') {
-                // First field with # description becomes "id"
-                "id".to_string()
-            } else if description.is_empty() {
-                // If description is empty, use original name or create meaningful name
-                if name == "key" {
-                    "id".to_string() // Fallback for key field
+            columns.push(Column {
+                index,
+                name: name.to_string(),
+                description: description.to_string(),
+                field_type,
+            });
+        }
+
+        let bit_runs = Self::find_bit_runs(&columns, csv_path)?;
+
+        let mut grouped_fields: HashMap<usize, GroupedField> =
+            Self::zip_sibling_groups(Self::find_array_groups(&columns))
+                .into_iter()
+                .map(|(first_column, group)| (first_column, group.into_grouped_field()))
+                .collect();
+
+        if self.nest_qualifiers {
+            let array_columns: HashSet<usize> = grouped_fields
+                .values()
+                .flat_map(|group| group.columns.iter().copied())
+                .collect();
+            grouped_fields.extend(Self::find_qualifier_groups(&columns, &array_columns));
+        }
+
+        if self.group_flags {
+            let used_columns: HashSet<usize> = grouped_fields
+                .values()
+                .flat_map(|group| group.columns.iter().copied())
+                .collect();
+            grouped_fields.extend(Self::find_flag_groups(&columns, &bit_runs, &used_columns));
+        }
+
+        let grouped_columns: HashSet<usize> = grouped_fields
+            .values()
+            .flat_map(|group| group.columns.iter().copied())
+            .collect();
+
+        let mut fields = Vec::new();
+        let mut used_names = HashSet::new();
+
+        for column in &columns {
+            let (mut field_name, field_type, field_columns, synthesized) =
+                if let Some(group) = grouped_fields.get(&column.index) {
+                    (
+                        Self::sanitize_field_name(&group.base),
+                        group.field_type.clone(),
+                        group.columns.clone(),
+                        group.synthesized,
+                    )
+                } else if grouped_columns.contains(&column.index) {
+                    // Remaining columns are covered by the grouped field at the first column
+                    continue;
                 } else {
-                    // For numeric field names, try to create a meaningful name
-                    if name.chars().all(|c| c.is_ascii_digit()) {
-                        format!("field{}", name)
-                    } else {
-                        name.to_string()
-                    }
-                }
-            } else if description == "Key" {
-                // Special handling for "Key" fields - treat as special type, use key as field name
-                "key".to_string()
-            } else {
-                Self::sanitize_field_name(description)
-            };
+                    (
+                        Self::field_name_for_column(&column.name, &column.description),
+                        column.field_type.clone(),
+                        vec![column.index],
+                        false,
+                    )
+                };
 
             // Handle duplicate field names by adding a suffix
             let mut counter = 1;
@@ -171,11 +343,22 @@ impl SchemaBuilder {
                 counter += 1;
             }
 
+            // Only names taken from the CSV are worth a warning
+            if field_name != original_name && !synthesized {
+                self.diagnostics.push(Diagnostic::FieldRenamed {
+                    path: csv_path.display().to_string(),
+                    column: field_columns[0],
+                    name: original_name,
+                    renamed: field_name.clone(),
+                });
+            }
+
             used_names.insert(field_name.clone());
 
             fields.push(Field {
                 name: field_name,
                 field_type,
+                columns: field_columns,
             });
         }
 
@@ -185,9 +368,406 @@ impl SchemaBuilder {
         })
     }
 
+    /// Determine the best field name for a single column
+    fn field_name_for_column(name: &str, description: &str) -> String {
+        if description.starts_with('#') {
+            // First field with # description becomes "id"
+            "id".to_string()
+        } else if description.is_empty() {
+            // If description is empty, use original name or create meaningful name
+            if name == "key" {
+                "id".to_string() // Fallback for key field
+            } else {
+                // For numeric field names, try to create a meaningful name
+                if name.chars().all(|c| c.is_ascii_digit()) {
+                    format!("field{}", name)
+                } else {
+                    name.to_string()
+                }
+            }
+        } else if description == "Key" {
+            // Special handling for "Key" fields - treat as special type, use key as field name
+            "key".to_string()
+        } else {
+            Self::sanitize_field_name(description)
+        }
+    }
+
+    /// Find `Name[n]` runs that can be collapsed into array fields.
+    ///
+    /// A run qualifies when its indices are exactly `0..n` and every element shares
+    /// the same type. Groups are keyed by the first CSV column of the run.
+    fn find_array_groups(columns: &[Column]) -> HashMap<usize, ArrayGroup> {
+        // Collect runs in order of first appearance
+        let mut runs: Vec<(String, Vec<(usize, &Column)>)> = Vec::new();
+        for column in columns {
+            let Some((base, element_index)) = parse_indexed_name(&column.description) else {
+                continue;
+            };
+            match runs.iter_mut().find(|(run_base, _)| run_base == base) {
+                Some((_, elements)) => elements.push((element_index, column)),
+                None => runs.push((base.to_string(), vec![(element_index, column)])),
+            }
+        }
+
+        runs.into_iter()
+            .filter_map(|(base, mut elements)| {
+                elements.sort_by_key(|(element_index, _)| *element_index);
+
+                let contiguous = elements
+                    .iter()
+                    .enumerate()
+                    .all(|(expected, (element_index, _))| expected == *element_index);
+                let element_type = elements[0].1.field_type.clone();
+                let consistent = elements
+                    .iter()
+                    .all(|(_, column)| column.field_type == element_type);
+
+                if !contiguous || !consistent {
+                    return None;
+                }
+
+                let columns: Vec<usize> = elements.iter().map(|(_, column)| column.index).collect();
+                let first_column = *columns.iter().min()?;
+                Some((
+                    first_column,
+                    ArrayGroup {
+                        base,
+                        element_type,
+                        length: columns.len(),
+                        columns,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Zip sibling array groups into arrays of inline records.
+    ///
+    /// Groups are siblings when they have the same length and their elements are
+    /// interleaved column by column, e.g. `BaseParam[i]` directly followed by
+    /// `BaseParamValue[i]`. The zipped group becomes `Array(Struct([param, value]), n)`.
+    fn zip_sibling_groups(groups: HashMap<usize, ArrayGroup>) -> HashMap<usize, ArrayGroup> {
+        let mut ordered: Vec<(usize, ArrayGroup)> = groups.into_iter().collect();
+        ordered.sort_by_key(|(first_column, _)| *first_column);
+
+        let mut zipped = HashMap::new();
+        let mut consumed = vec![false; ordered.len()];
+
+        for leader in 0..ordered.len() {
+            if consumed[leader] {
+                continue;
+            }
+
+            let (first_column, group) = &ordered[leader];
+            let mut siblings = vec![leader];
+            // Siblings start between the leader's first and second elements
+            if group.length >= 2 {
+                siblings.extend((leader + 1..ordered.len()).filter(|&candidate| {
+                    let (_, other) = &ordered[candidate];
+                    !consumed[candidate]
+                        && other.length == group.length
+                        && other.columns[0] < group.columns[1]
+                }));
+            }
+
+            let members: Vec<&ArrayGroup> = siblings.iter().map(|&i| &ordered[i].1).collect();
+            let names = Self::sibling_names(&members);
+
+            match names {
+                Some((base, member_names)) if Self::is_interleaved(&members) => {
+                    let element_type = FieldType::Struct(
+                        member_names
+                            .into_iter()
+                            .zip(&members)
+                            .map(|(name, member)| (name, member.element_type.clone()))
+                            .collect(),
+                    );
+                    let columns = (0..group.length)
+                        .flat_map(|element| {
+                            members.iter().map(move |member| member.columns[element])
+                        })
+                        .collect();
+
+                    zipped.insert(
+                        *first_column,
+                        ArrayGroup {
+                            base,
+                            element_type,
+                            length: group.length,
+                            columns,
+                        },
+                    );
+                    for &sibling in &siblings {
+                        consumed[sibling] = true;
+                    }
+                }
+                _ => {
+                    consumed[leader] = true;
+                    zipped.insert(*first_column, group.clone());
+                }
+            }
+        }
+
+        zipped
+    }
+
+    /// Check that element `i` of every member occupies one contiguous block of
+    /// columns, in member order, and that the blocks follow each other
+    fn is_interleaved(members: &[&ArrayGroup]) -> bool {
+        if members.len() < 2 {
+            return false;
+        }
+
+        let starts: Vec<usize> = (0..members[0].length)
+            .map(|element| members[0].columns[element])
+            .collect();
+
+        let blocks_contiguous = starts.iter().enumerate().all(|(element, &start)| {
+            members
+                .iter()
+                .enumerate()
+                .all(|(offset, member)| member.columns[element] == start + offset)
+        });
+
+        blocks_contiguous && starts.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    /// Derive the shared field base and member names for zipped sibling groups.
+    ///
+    /// `BaseParam{Special}` + `BaseParamValue{Special}` share the words `Base Param`
+    /// and the qualifier `{Special}`, giving the field base `BaseParam{Special}` and
+    /// members `param` and `value`. Returns `None` when the bases share no words,
+    /// differ in qualifiers, or would produce duplicate member names.
+    fn sibling_names(members: &[&ArrayGroup]) -> Option<(String, Vec<String>)> {
+        let split: Vec<(Vec<&str>, String)> = members
+            .iter()
+            .map(|member| split_qualified_words(&member.base))
+            .collect();
+
+        let qualifiers = &split[0].1;
+        if split.iter().any(|(_, other)| other != qualifiers) {
+            return None;
+        }
+
+        let first_words = &split[0].0;
+        let prefix_len = (0..first_words.len())
+            .take_while(|&i| {
+                split
+                    .iter()
+                    .all(|(words, _)| words.get(i) == first_words.get(i))
+            })
+            .count();
+        if prefix_len == 0 {
+            return None;
+        }
+
+        let member_names: Vec<String> = split
+            .iter()
+            .map(|(words, _)| {
+                let rest = &words[prefix_len..];
+                let member = if rest.is_empty() {
+                    words[prefix_len - 1..prefix_len].concat()
+                } else {
+                    rest.concat()
+                };
+                Self::sanitize_field_name(&member)
+            })
+            .collect();
+
+        let unique: HashSet<&String> = member_names.iter().collect();
+        if unique.len() != member_names.len() {
+            return None;
+        }
+
+        let base = format!("{}{}", first_words[..prefix_len].concat(), qualifiers);
+        Some((base, member_names))
+    }
+
+    /// Find `Base{Qualifier}` columns sharing a base name and nest them into one
+    /// object field, e.g. `Price{Mid}` + `Price{Low}` become `price: { mid, low }`.
+    ///
+    /// Columns already used by array fields are skipped, and a base needs at least
+    /// two distinct qualifiers to be nested.
+    fn find_qualifier_groups(
+        columns: &[Column],
+        excluded: &HashSet<usize>,
+    ) -> HashMap<usize, GroupedField> {
+        // Collect groups in order of first appearance
+        let mut groups: Vec<(&str, Vec<(&str, &Column)>)> = Vec::new();
+        for column in columns {
+            if excluded.contains(&column.index) {
+                continue;
+            }
+            let Some((base, qualifier)) = parse_qualified_name(&column.description) else {
+                continue;
+            };
+            match groups
+                .iter_mut()
+                .find(|(group_base, _)| *group_base == base)
+            {
+                Some((_, members)) => members.push((qualifier, column)),
+                None => groups.push((base, vec![(qualifier, column)])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .filter(|(_, members)| members.len() >= 2)
+            .filter_map(|(base, members)| {
+                let member_names: Vec<String> = members
+                    .iter()
+                    .map(|(qualifier, _)| Self::sanitize_field_name(qualifier))
+                    .collect();
+
+                let unique: HashSet<&String> = member_names.iter().collect();
+                if unique.len() != member_names.len() {
+                    return None;
+                }
+
+                let field_type = FieldType::Struct(
+                    member_names
+                        .into_iter()
+                        .zip(&members)
+                        .map(|(name, (_, column))| (name, column.field_type.clone()))
+                        .collect(),
+                );
+                let columns: Vec<usize> = members.iter().map(|(_, column)| column.index).collect();
+
+                Some((
+                    columns[0],
+                    GroupedField {
+                        base: base.to_string(),
+                        field_type,
+                        columns,
+                        synthesized: false,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Split bit columns into runs that share a byte and validate their masks.
+    ///
+    /// A run starts at the first bit column, at every `bit&01`, and after a non-bit
+    /// column whose following mask cannot continue the current byte. Within
+    /// adjacent bit columns each mask must be higher than the previous one and must
+    /// not overlap the bits already used; otherwise the flags collide or repeat out
+    /// of order. Returns the column indices of each run.
+    fn find_bit_runs(columns: &[Column], csv_path: &Path) -> Result<Vec<Vec<usize>>, SchemaError> {
+        let mut runs: Vec<Vec<usize>> = Vec::new();
+        let mut previous_mask = 0u8;
+        let mut used_bits = 0u8;
+        let mut previous_index: Option<usize> = None;
+
+        for column in columns {
+            let FieldType::Bit(mask) = column.field_type else {
+                continue;
+            };
+            let separated = previous_index.is_some_and(|index| column.index != index + 1);
+            previous_index = Some(column.index);
+
+            let conflict = |reason: &str| SchemaError::BitMaskConflict {
+                mask,
+                column: column.index,
+                path: csv_path.display().to_string(),
+                reason: reason.to_string(),
+            };
+
+            if mask == 0 {
+                return Err(conflict("mask must set at least one bit"));
+            }
+
+            let continues_byte = mask > previous_mask && mask & used_bits == 0;
+            if runs.is_empty() || mask == 0x01 || (separated && !continues_byte) {
+                runs.push(Vec::new());
+                previous_mask = 0;
+                used_bits = 0;
+            }
+
+            if mask & used_bits != 0 {
+                return Err(conflict("mask overlaps bits already used in this byte"));
+            }
+            if mask <= previous_mask {
+                return Err(conflict("mask is out of order within this byte"));
+            }
+
+            previous_mask = mask;
+            used_bits |= mask;
+            if let Some(run) = runs.last_mut() {
+                run.push(column.index);
+            }
+        }
+
+        Ok(runs)
+    }
+
+    /// Group adjacent bit columns of the same run into a `flags` object field.
+    ///
+    /// Only runs of at least two neighbouring columns are grouped; isolated bit
+    /// columns stay plain boolean fields. A single group is named `flags`; several
+    /// are numbered `flags1`, `flags2`, ... in column order.
+    fn find_flag_groups(
+        columns: &[Column],
+        bit_runs: &[Vec<usize>],
+        excluded: &HashSet<usize>,
+    ) -> HashMap<usize, GroupedField> {
+        let mut segments: Vec<Vec<usize>> = Vec::new();
+        for run in bit_runs {
+            let mut run_segments: Vec<Vec<usize>> = Vec::new();
+            for &index in run.iter().filter(|index| !excluded.contains(index)) {
+                match run_segments.last_mut() {
+                    Some(segment) if segment.last() == Some(&(index - 1)) => segment.push(index),
+                    _ => run_segments.push(vec![index]),
+                }
+            }
+            segments.extend(
+                run_segments
+                    .into_iter()
+                    .filter(|segment| segment.len() >= 2),
+            );
+        }
+
+        let numbered = segments.len() > 1;
+        segments
+            .into_iter()
+            .enumerate()
+            .map(|(position, segment)| {
+                let field_type = FieldType::Struct(
+                    segment
+                        .iter()
+                        .map(|&index| {
+                            let column = &columns[index];
+                            (
+                                Self::field_name_for_column(&column.name, &column.description),
+                                column.field_type.clone(),
+                            )
+                        })
+                        .collect(),
+                );
+                let base = if numbered {
+                    format!("Flags{}", position + 1)
+                } else {
+                    "Flags".to_string()
+                };
+
+                (
+                    segment[0],
+                    GroupedField {
+                        base,
+                        field_type,
+                        columns: segment,
+                        synthesized: true,
+                    },
+                )
+            })
+            .collect()
+    }
+
     fn parse_field_type(
         &mut self,
         type_str: &str,
+        column: usize,
         base_dir: &Path,
         current_file: &Path,
     ) -> Result<FieldType, SchemaError> {
@@ -198,6 +778,8 @@ impl SchemaBuilder {
             "str" => Ok(FieldType::String),
             "int32" => Ok(FieldType::Int32),
             "uint32" => Ok(FieldType::Uint32),
+            "int64" => Ok(FieldType::Int64),
+            "uint64" => Ok(FieldType::Uint64),
             "int16" => Ok(FieldType::Int16),
             "uint16" => Ok(FieldType::Uint16),
             "byte" => Ok(FieldType::Byte),
@@ -225,8 +807,28 @@ impl SchemaBuilder {
                     self.build_schema_recursive(trimmed, base_dir, current_file)?;
                     Ok(FieldType::Custom(trimmed.to_string()))
                 }
-                // Unknown types default to string
+                // Unknown types are rejected in strict mode
+                else if self.strict {
+                    let error = SchemaError::UnknownType {
+                        token: trimmed.to_string(),
+                        column,
+                        path: current_file.display().to_string(),
+                    };
+                    if self.keep_going {
+                        // Keep reading the sheet to find its other unknown types
+                        self.errors.push(error);
+                        Ok(FieldType::String)
+                    } else {
+                        Err(error)
+                    }
+                }
+                // Otherwise they default to string and are recorded for a warning
                 else {
+                    self.diagnostics.push(Diagnostic::TypeCoerced(TypeCoercion {
+                        path: current_file.display().to_string(),
+                        column,
+                        token: trimmed.to_string(),
+                    }));
                     Ok(FieldType::String)
                 }
             }
@@ -237,6 +839,25 @@ impl SchemaBuilder {
         &self.schemas
     }
 
+    pub fn get_all_rows(&self) -> &RowMap {
+        &self.rows
+    }
+
+    /// Unknown type tokens that were coerced to string (always empty in strict mode)
+    pub fn get_type_coercions(&self) -> impl Iterator<Item = &TypeCoercion> {
+        self.diagnostics
+            .iter()
+            .filter_map(|diagnostic| match diagnostic {
+                Diagnostic::TypeCoerced(coercion) => Some(coercion),
+                _ => None,
+            })
+    }
+
+    /// Everything noteworthy that happened while building, in the order it happened
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
     /// Find the row index for field names based on header indicator
     fn find_field_names_row(
         records: &[csv::StringRecord],
@@ -314,16 +935,6 @@ impl SchemaBuilder {
         }
     }
 
-    pub fn print_schemas(&self) {
-        for schema in self.schemas.values() {
-            println!("Schema: {}", schema.name);
-            for field in &schema.fields {
-                println!("  {}: {:?}", field.name, field.field_type);
-            }
-            println!();
-        }
-    }
-
     /// Sanitize field name to make it a valid TypeScript identifier
     fn sanitize_field_name(name: &str) -> String {
         let mut result = String::new();
@@ -340,11 +951,9 @@ impl SchemaBuilder {
                     result.push(ch);
                 }
                 // Convert various separators to camelCase
-                '{' | '}' | ' ' | '-' | '.' => {
-                    if !result.is_empty() && !result.ends_with('_') {
-                        // Next character should be uppercase for camelCase
-                        first_char = false;
-                    }
+                // Next character should be uppercase for camelCase
+                '{' | '}' | ' ' | '-' | '.' if !result.is_empty() && !result.ends_with('_') => {
+                    first_char = false;
                 }
                 _ => {
                     // Skip other characters
@@ -397,7 +1006,7 @@ impl Default for SchemaBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::schema::types::Severity;
     use std::io::Write;
     use tempfile::TempDir;
 
@@ -438,6 +1047,490 @@ mod tests {
         assert_eq!(schema.fields[3].field_type, FieldType::Bool);
     }
 
+    #[test]
+    fn test_data_rows_collected() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1\n#,Name,Level\nint32,str,byte\n1,\"Sword\",10\n2,\"Shield\",5";
+        let file_path = create_test_csv(&temp_dir, "Rows", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let rows = &builder.get_all_rows()["Rows"];
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], vec!["1", "Sword", "10"]);
+        assert_eq!(rows[1], vec!["2", "Shield", "5"]);
+    }
+
+    #[test]
+    fn test_build_schemas_from_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_csv(
+            &temp_dir,
+            "Item",
+            "key,0,1\n#,Name,ItemLevel\nint32,str,ItemLevel\n1,\"Sword\",1",
+        );
+        create_test_csv(
+            &temp_dir,
+            "ItemLevel",
+            "key,0\n#,Strength\nint32,uint16\n1,10",
+        );
+        create_test_csv(&temp_dir, "Quest", "key,0\n#,Name\nint32,str\n1,\"Intro\"");
+        fs::write(temp_dir.path().join("notes.txt"), "not a sheet").unwrap();
+
+        let mut builder = SchemaBuilder::new();
+        let names = builder.build_schemas_from_dir(temp_dir.path()).unwrap();
+
+        assert_eq!(names, vec!["Item", "ItemLevel", "Quest"]);
+        assert_eq!(builder.get_all_schemas().len(), 3);
+        assert_eq!(builder.get_all_rows()["Quest"].len(), 1);
+    }
+
+    #[test]
+    fn test_diagnostics_record_loaded_sheets_and_coercions() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = create_test_csv(
+            &temp_dir,
+            "Item",
+            "key,0,1\n#,Name,ItemLevel\nint32,decimal,ItemLevel\n1,\"Sword\",1\n2,\"Axe\",1",
+        );
+        create_test_csv(
+            &temp_dir,
+            "ItemLevel",
+            "key,0\n#,Strength\nint32,uint16\n1,10",
+        );
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let diagnostics = builder.diagnostics();
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].severity(), Severity::Warning);
+        assert_eq!(
+            diagnostics[1],
+            Diagnostic::SheetLoaded {
+                sheet: "ItemLevel".to_string(),
+                path: temp_dir.path().join("ItemLevel.csv").display().to_string(),
+                rows: 1,
+            }
+        );
+        assert_eq!(
+            diagnostics[2].to_string(),
+            format!("Loaded Item from {} (2 rows)", file_path.display())
+        );
+    }
+
+    #[test]
+    fn test_keep_going_collects_every_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = create_test_csv(
+            &temp_dir,
+            "Item",
+            "key,0,1,2,3\n#,Name,ItemLevel,Rarity,ItemLevel\nint32,str,ItemLevel,Rarity,ItemLevel\n1,\"Sword\",1,2,1",
+        );
+        create_test_csv(&temp_dir, "Rarity", "0,1\nint32,str\n1,\"Common\"");
+
+        let mut builder = SchemaBuilder::new().with_keep_going(true);
+        let result = builder.build_schema_from_file(&file_path);
+
+        match result {
+            Err(SchemaError::Multiple { errors }) => {
+                assert_eq!(errors.len(), 2);
+                assert!(
+                    matches!(&errors[0], SchemaError::FileNotFound { path, .. } if path.ends_with("ItemLevel.csv"))
+                );
+                assert!(matches!(
+                    &errors[1],
+                    SchemaError::MissingCsvHeader { header, .. } if header == "key"
+                ));
+            }
+            other => panic!("Expected Multiple error, got {:?}", other),
+        }
+
+        // The sheet itself was still built, referencing the broken sheets by name
+        let schema = &builder.get_all_schemas()["Item"];
+        assert_eq!(
+            schema.fields[3].field_type,
+            FieldType::Custom("Rarity".to_string())
+        );
+        assert!(!builder.get_all_rows().contains_key("Rarity"));
+    }
+
+    #[test]
+    fn test_keep_going_collects_unknown_types() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2\n#,Name,Weight,Size\nint32,str,decimal,huge\n1,\"Sword\",1.5,2";
+        let file_path = create_test_csv(&temp_dir, "Unknown", content);
+
+        let mut builder = SchemaBuilder::new().with_strict(true).with_keep_going(true);
+        let result = builder.build_schema_from_file(&file_path);
+
+        match result {
+            Err(SchemaError::Multiple { errors }) => {
+                let tokens: Vec<_> = errors
+                    .iter()
+                    .map(|error| match error {
+                        SchemaError::UnknownType { token, .. } => token.as_str(),
+                        other => panic!("Expected UnknownType error, got {:?}", other),
+                    })
+                    .collect();
+                assert_eq!(tokens, vec!["decimal", "huge"]);
+            }
+            other => panic!("Expected Multiple error, got {:?}", other),
+        }
+
+        // A single collected error is returned as itself
+        let mut builder = SchemaBuilder::new().with_keep_going(true);
+        let result = builder.build_schema_from_file(temp_dir.path().join("Missing.csv"));
+        assert!(matches!(result, Err(SchemaError::FileNotFound { .. })));
+    }
+
+    #[test]
+    fn test_duplicate_field_names_renamed() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1\n#,Name,Name\nint32,str,str\n1,\"Sword\",\"Blade\"";
+        let file_path = create_test_csv(&temp_dir, "Twice", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Twice"];
+        assert_eq!(schema.fields[1].name, "name");
+        assert_eq!(schema.fields[2].name, "name1");
+        assert_eq!(
+            builder.diagnostics()[0],
+            Diagnostic::FieldRenamed {
+                path: file_path.display().to_string(),
+                column: 2,
+                name: "name".to_string(),
+                renamed: "name1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_type_coerced_to_string() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1\n#,Name,Weight\nint32,str,decimal\n1,\"Sword\",1.5";
+        let file_path = create_test_csv(&temp_dir, "Unknown", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Unknown"];
+        assert_eq!(schema.fields[2].field_type, FieldType::String);
+
+        let coercions: Vec<_> = builder.get_type_coercions().collect();
+        assert_eq!(coercions.len(), 1);
+        assert_eq!(coercions[0].token, "decimal");
+        assert_eq!(coercions[0].column, 2);
+        assert_eq!(coercions[0].path, file_path.display().to_string());
+    }
+
+    #[test]
+    fn test_unknown_type_rejected_in_strict_mode() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1\n#,Name,Weight\nint32,str,decimal\n1,\"Sword\",1.5";
+        let file_path = create_test_csv(&temp_dir, "Unknown", content);
+
+        let mut builder = SchemaBuilder::new().with_strict(true);
+        let result = builder.build_schema_from_file(&file_path);
+
+        match result {
+            Err(SchemaError::UnknownType {
+                token,
+                column,
+                path,
+            }) => {
+                assert_eq!(token, "decimal");
+                assert_eq!(column, 2);
+                assert_eq!(path, file_path.display().to_string());
+            }
+            other => panic!("Expected UnknownType error, got {:?}", other),
+        }
+        assert_eq!(builder.get_type_coercions().count(), 0);
+    }
+
+    #[test]
+    fn test_indexed_columns_grouped_into_arrays() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2,3,4\n#,Name,Param[0],Param[1],ParamValue[0],ParamValue[1]\nint32,str,byte,byte,int16,int16\n1,\"Sword\",1,2,10,-5";
+        let file_path = create_test_csv(&temp_dir, "Indexed", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Indexed"];
+        assert_eq!(schema.fields.len(), 4);
+
+        assert_eq!(schema.fields[2].name, "param");
+        assert_eq!(
+            schema.fields[2].field_type,
+            FieldType::Array(Box::new(FieldType::Byte), 2)
+        );
+        assert_eq!(schema.fields[2].columns, vec![2, 3]);
+
+        assert_eq!(schema.fields[3].name, "paramValue");
+        assert_eq!(
+            schema.fields[3].field_type,
+            FieldType::Array(Box::new(FieldType::Int16), 2)
+        );
+        assert_eq!(schema.fields[3].columns, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_interleaved_indexed_columns_zipped_into_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2,3,4,5,6,7,8\n#,Name,Param[0],ParamValue[0],Param[1],ParamValue[1],Param{Special}[0],ParamValue{Special}[0],Param{Special}[1],ParamValue{Special}[1]\nint32,str,byte,int16,byte,int16,byte,int16,byte,int16\n1,\"Sword\",1,10,2,-5,3,7,0,0";
+        let file_path = create_test_csv(&temp_dir, "Zipped", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Zipped"];
+        assert_eq!(schema.fields.len(), 4);
+
+        let record = FieldType::Struct(vec![
+            ("param".to_string(), FieldType::Byte),
+            ("value".to_string(), FieldType::Int16),
+        ]);
+
+        assert_eq!(schema.fields[2].name, "param");
+        assert_eq!(
+            schema.fields[2].field_type,
+            FieldType::Array(Box::new(record.clone()), 2)
+        );
+        assert_eq!(schema.fields[2].columns, vec![2, 3, 4, 5]);
+
+        assert_eq!(schema.fields[3].name, "paramSpecial");
+        assert_eq!(
+            schema.fields[3].field_type,
+            FieldType::Array(Box::new(record), 2)
+        );
+        assert_eq!(schema.fields[3].columns, vec![6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_qualifier_columns_flat_by_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let content =
+            "key,0,1,2\n#,Name,Price{Mid},Price{Low}\nint32,str,uint32,uint32\n1,\"Sword\",100,10";
+        let file_path = create_test_csv(&temp_dir, "Flat", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Flat"];
+        assert_eq!(schema.fields.len(), 4);
+        assert_eq!(schema.fields[2].name, "priceMid");
+        assert_eq!(schema.fields[3].name, "priceLow");
+    }
+
+    #[test]
+    fn test_qualifier_columns_nested_when_enabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2,3,4\n#,Price{Mid},Level{Item},Price{Low},Level{Equip},ItemSpecialBonus{Param}\nint32,uint32,byte,uint32,byte,byte\n1,100,5,10,1,0";
+        let file_path = create_test_csv(&temp_dir, "Nested", content);
+
+        let mut builder = SchemaBuilder::new().with_nested_qualifiers(true);
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Nested"];
+        assert_eq!(schema.fields.len(), 4);
+
+        assert_eq!(schema.fields[1].name, "price");
+        assert_eq!(
+            schema.fields[1].field_type,
+            FieldType::Struct(vec![
+                ("mid".to_string(), FieldType::Uint32),
+                ("low".to_string(), FieldType::Uint32),
+            ])
+        );
+        assert_eq!(schema.fields[1].columns, vec![1, 3]);
+
+        assert_eq!(schema.fields[2].name, "level");
+        assert_eq!(
+            schema.fields[2].field_type,
+            FieldType::Struct(vec![
+                ("item".to_string(), FieldType::Byte),
+                ("equip".to_string(), FieldType::Byte),
+            ])
+        );
+        assert_eq!(schema.fields[2].columns, vec![2, 4]);
+
+        // A single qualified column stays flat
+        assert_eq!(schema.fields[3].name, "itemSpecialBonusParam");
+        assert_eq!(schema.fields[3].field_type, FieldType::Byte);
+    }
+
+    #[test]
+    fn test_bit_columns_stay_flat_by_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let content =
+            "key,0,1,2\n#,IsUnique,IsUntradable,Lot\nint32,bit&01,bit&02,byte\n1,True,False,0";
+        let file_path = create_test_csv(&temp_dir, "Bits", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Bits"];
+        assert_eq!(schema.fields.len(), 4);
+        assert_eq!(schema.fields[1].name, "isUnique");
+        assert_eq!(schema.fields[1].field_type, FieldType::Bit(0x01));
+        assert_eq!(schema.fields[2].name, "isUntradable");
+        assert_eq!(schema.fields[2].field_type, FieldType::Bit(0x02));
+    }
+
+    #[test]
+    fn test_adjacent_bit_columns_grouped_into_flags() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2,3,4,5\n#,IsUnique,IsUntradable,Lot,CanBeHq,IsPvP,IsGlamourous\nint32,bit&01,bit&02,byte,bit&04,bit&01,bit&02\n1,True,False,0,True,False,False";
+        let file_path = create_test_csv(&temp_dir, "Flags", content);
+
+        let mut builder = SchemaBuilder::new().with_grouped_flags(true);
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Flags"];
+        assert_eq!(schema.fields.len(), 5);
+
+        assert_eq!(schema.fields[1].name, "flags1");
+        assert_eq!(
+            schema.fields[1].field_type,
+            FieldType::Struct(vec![
+                ("isUnique".to_string(), FieldType::Bit(0x01)),
+                ("isUntradable".to_string(), FieldType::Bit(0x02)),
+            ])
+        );
+        assert_eq!(schema.fields[1].columns, vec![1, 2]);
+
+        // An isolated bit column stays a plain field
+        assert_eq!(schema.fields[3].name, "canBeHq");
+        assert_eq!(schema.fields[3].field_type, FieldType::Bit(0x04));
+
+        // The second byte starts at bit&01 and becomes its own flags object
+        assert_eq!(schema.fields[4].name, "flags2");
+        assert_eq!(schema.fields[4].columns, vec![5, 6]);
+
+        // Generated names are distinct, so nothing is reported as renamed
+        assert!(
+            !builder
+                .diagnostics()
+                .iter()
+                .any(|diagnostic| matches!(diagnostic, Diagnostic::FieldRenamed { .. }))
+        );
+    }
+
+    #[test]
+    fn test_single_flag_group_named_flags() {
+        let temp_dir = TempDir::new().unwrap();
+        let content =
+            "key,0,1,2\n#,IsUnique,IsUntradable,Lot\nint32,bit&01,bit&02,byte\n1,True,False,0";
+        let file_path = create_test_csv(&temp_dir, "Flags", content);
+
+        let mut builder = SchemaBuilder::new().with_grouped_flags(true);
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Flags"];
+        assert_eq!(schema.fields[1].name, "flags");
+        assert_eq!(schema.fields[2].name, "lot");
+    }
+
+    #[test]
+    fn test_bit_mask_collision_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2\n#,A,B,C\nint32,bit&01,bit&03,bit&04\n1,True,False,True";
+        let file_path = create_test_csv(&temp_dir, "Collide", content);
+
+        let mut builder = SchemaBuilder::new();
+        let result = builder.build_schema_from_file(&file_path);
+
+        match result {
+            Err(SchemaError::BitMaskConflict { mask, column, .. }) => {
+                assert_eq!(mask, 0x03);
+                assert_eq!(column, 2);
+            }
+            other => panic!("Expected BitMaskConflict error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bit_mask_out_of_order_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2\n#,A,B,C\nint32,bit&01,bit&04,bit&02\n1,True,False,True";
+        let file_path = create_test_csv(&temp_dir, "OutOfOrder", content);
+
+        let mut builder = SchemaBuilder::new();
+        let result = builder.build_schema_from_file(&file_path);
+
+        match result {
+            Err(SchemaError::BitMaskConflict { mask, column, .. }) => {
+                assert_eq!(mask, 0x02);
+                assert_eq!(column, 3);
+            }
+            other => panic!("Expected BitMaskConflict error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_bit_column_starts_new_byte() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2,3,4\n#,A,Lot,B,C,D\nint32,bit&80,byte,bit&02,uint32,bit&04\n1,True,0,False,1,True";
+        let file_path = create_test_csv(&temp_dir, "Separated", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+        assert_eq!(builder.get_all_schemas()["Separated"].fields.len(), 6);
+
+        // bit&02 cannot follow bit&80, so it starts the next byte; bit&04 continues it
+        let columns: Vec<Column> = ["int32", "bit&80", "byte", "bit&02", "uint32", "bit&04"]
+            .iter()
+            .enumerate()
+            .map(|(index, type_str)| Column {
+                index,
+                name: index.to_string(),
+                description: String::new(),
+                field_type: match type_str.strip_prefix("bit&") {
+                    Some(mask) => FieldType::Bit(u8::from_str_radix(mask, 16).unwrap()),
+                    None => FieldType::Int32,
+                },
+            })
+            .collect();
+        let runs = SchemaBuilder::find_bit_runs(&columns, &file_path).unwrap();
+        assert_eq!(runs, vec![vec![1], vec![3, 5]]);
+    }
+
+    #[test]
+    fn test_indexed_columns_with_mixed_types_not_grouped() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2\n#,Name,Slot[0],Slot[1]\nint32,str,byte,str\n1,\"Sword\",1,\"a\"";
+        let file_path = create_test_csv(&temp_dir, "Mixed", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Mixed"];
+        assert_eq!(schema.fields.len(), 4);
+        assert_eq!(schema.fields[2].name, "slot0");
+        assert_eq!(schema.fields[2].field_type, FieldType::Byte);
+        assert_eq!(schema.fields[3].name, "slot1");
+        assert_eq!(schema.fields[3].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_indexed_columns_with_gaps_not_grouped() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "key,0,1,2\n#,Name,Slot[0],Slot[2]\nint32,str,byte,byte\n1,\"Sword\",1,2";
+        let file_path = create_test_csv(&temp_dir, "Gaps", content);
+
+        let mut builder = SchemaBuilder::new();
+        builder.build_schema_from_file(&file_path).unwrap();
+
+        let schema = &builder.get_all_schemas()["Gaps"];
+        assert_eq!(schema.fields.len(), 4);
+        assert_eq!(schema.fields[2].field_type, FieldType::Byte);
+        assert_eq!(schema.fields[3].field_type, FieldType::Byte);
+    }
+
     #[test]
     fn test_recursive_schema_building() {
         let temp_dir = TempDir::new().unwrap();
@@ -643,8 +1736,7 @@ mod tests {
     #[test]
     fn test_color_type_parsing() {
         let temp_dir = TempDir::new().unwrap();
-        let content =
-            "key,0,1,2\n#,Name,Description,BackgroundColor\nint32,str,str,Color\n1,\"Fire Element\",\"Element of fire\",16711680\n2,\"Water Element\",\"Element of water\",255";
+        let content = "key,0,1,2\n#,Name,Description,BackgroundColor\nint32,str,str,Color\n1,\"Fire Element\",\"Element of fire\",16711680\n2,\"Water Element\",\"Element of water\",255";
         let file_path = create_test_csv(&temp_dir, "ColorTest", content);
 
         let mut builder = SchemaBuilder::new();
@@ -674,8 +1766,7 @@ mod tests {
     #[test]
     fn test_mixed_special_types_with_color() {
         let temp_dir = TempDir::new().unwrap();
-        let content =
-            "key,0,1,2,3,4\n#,Name,Icon,Ref,TextColor,Status\nint32,str,Image,Row,Color,Key\n1,\"Test Item\",\"021001\",0,16777215,\"active\"";
+        let content = "key,0,1,2,3,4\n#,Name,Icon,Ref,TextColor,Status\nint32,str,Image,Row,Color,Key\n1,\"Test Item\",\"021001\",0,16777215,\"active\"";
         let file_path = create_test_csv(&temp_dir, "MixedSpecialTypes", content);
 
         let mut builder = SchemaBuilder::new();
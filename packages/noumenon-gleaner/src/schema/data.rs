@@ -0,0 +1,801 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value as JsonValue};
+
+use super::error::SchemaError;
+use super::types::{FieldType, Int64Representation, RowMap, Schema, SchemaMap};
+use crate::constants::ZERO_SENTINEL;
+
+pub struct DataExporter {
+    int64_representation: Int64Representation,
+    inline_depth: usize,
+    inline_fields: HashMap<String, Vec<String>>,
+}
+
+/// Extracted rows of every sheet, indexed by row key for reference lookups
+struct ExtractedSheets {
+    rows: HashMap<String, Vec<JsonValue>>,
+    keys: HashMap<String, HashMap<String, usize>>,
+}
+
+impl ExtractedSheets {
+    fn find(&self, sheet: &str, key: &str) -> Option<&JsonValue> {
+        let index = *self.keys.get(sheet)?.get(key)?;
+        self.rows.get(sheet)?.get(index)
+    }
+}
+
+impl DataExporter {
+    pub fn new() -> Self {
+        Self {
+            int64_representation: Int64Representation::default(),
+            inline_depth: 1,
+            inline_fields: HashMap::new(),
+        }
+    }
+
+    /// Set how 64-bit integer values are written to JSON
+    pub fn with_int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
+
+    /// Set how many levels of references are followed when inlining rows
+    pub fn with_inline_depth(mut self, depth: usize) -> Self {
+        self.inline_depth = depth;
+        self
+    }
+
+    /// Inline references to the given sheet as objects holding only the listed fields
+    pub fn with_inline_fields(mut self, sheet: impl Into<String>, fields: Vec<String>) -> Self {
+        self.inline_fields.insert(sheet.into(), fields);
+        self
+    }
+
+    /// Extract data rows for all schemas and save one JSON file per sheet
+    pub fn export_and_save<P: AsRef<Path>>(
+        &self,
+        schemas: &SchemaMap,
+        rows: &RowMap,
+        output_dir: P,
+    ) -> Result<Vec<PathBuf>, SchemaError> {
+        self.validate_inline_fields(schemas)?;
+
+        let dir = output_dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let sheets = self.extract_sheets(schemas, rows)?;
+
+        let mut written = Vec::new();
+        for schema in sorted_schemas {
+            let data = JsonValue::Array(self.inline_sheet(schema, schemas, &sheets));
+
+            let path = dir.join(format!("{}.json", schema.name));
+            let content = serde_json::to_string_pretty(&data).map_err(std::io::Error::from)?;
+            fs::write(&path, content)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+
+    /// Convert data rows into JSON objects keyed by the schema's field names
+    pub fn extract_rows(
+        &self,
+        schema: &Schema,
+        records: &[csv::StringRecord],
+    ) -> Result<Vec<JsonValue>, SchemaError> {
+        self.rows(schema, records).collect()
+    }
+
+    /// Iterate data rows as JSON objects, converting each row only when it is reached
+    pub fn rows<'a>(
+        &'a self,
+        schema: &'a Schema,
+        records: &'a [csv::StringRecord],
+    ) -> impl Iterator<Item = Result<JsonValue, SchemaError>> + 'a {
+        records
+            .iter()
+            .map(move |record| self.extract_row(schema, record))
+    }
+
+    /// Extract every sheet up front so references can be resolved across sheets
+    fn extract_sheets(
+        &self,
+        schemas: &SchemaMap,
+        rows: &RowMap,
+    ) -> Result<ExtractedSheets, SchemaError> {
+        let mut sheets = ExtractedSheets {
+            rows: HashMap::new(),
+            keys: HashMap::new(),
+        };
+
+        for schema in schemas.values() {
+            let records = rows.get(&schema.name).map(Vec::as_slice).unwrap_or(&[]);
+            let keys = records
+                .iter()
+                .enumerate()
+                .map(|(index, record)| {
+                    (record.get(0).unwrap_or_default().trim().to_string(), index)
+                })
+                .collect();
+
+            sheets
+                .rows
+                .insert(schema.name.clone(), self.extract_rows(schema, records)?);
+            sheets.keys.insert(schema.name.clone(), keys);
+        }
+
+        Ok(sheets)
+    }
+
+    /// Ensure every whitelisted sheet and field exists before exporting
+    fn validate_inline_fields(&self, schemas: &SchemaMap) -> Result<(), SchemaError> {
+        for (sheet, fields) in &self.inline_fields {
+            let schema = schemas
+                .get(sheet)
+                .ok_or_else(|| SchemaError::InvalidInlineField {
+                    sheet: sheet.clone(),
+                    field: fields.join(","),
+                    reason: "sheet is not part of the schema graph".to_string(),
+                })?;
+
+            if let Some(field) = fields
+                .iter()
+                .find(|field| !schema.fields.iter().any(|f| &f.name == *field))
+            {
+                return Err(SchemaError::InvalidInlineField {
+                    sheet: sheet.clone(),
+                    field: field.clone(),
+                    reason: "field does not exist".to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rows of a sheet with whitelisted references replaced by the referenced rows
+    fn inline_sheet(
+        &self,
+        schema: &Schema,
+        schemas: &SchemaMap,
+        sheets: &ExtractedSheets,
+    ) -> Vec<JsonValue> {
+        let rows = sheets
+            .rows
+            .get(&schema.name)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        if self.inline_fields.is_empty() || self.inline_depth == 0 {
+            return rows.to_vec();
+        }
+
+        // The sheet itself starts the path so self-references are cut immediately
+        let mut path = vec![schema.name.clone()];
+        rows.iter()
+            .map(|row| {
+                let mut object = Map::new();
+                for field in &schema.fields {
+                    let value = &row[&field.name];
+                    let value = self.inline_value(
+                        value,
+                        &field.field_type,
+                        schemas,
+                        sheets,
+                        self.inline_depth,
+                        &mut path,
+                    );
+                    object.insert(field.name.clone(), value);
+                }
+                JsonValue::Object(object)
+            })
+            .collect()
+    }
+
+    fn inline_value(
+        &self,
+        value: &JsonValue,
+        field_type: &FieldType,
+        schemas: &SchemaMap,
+        sheets: &ExtractedSheets,
+        depth: usize,
+        path: &mut Vec<String>,
+    ) -> JsonValue {
+        match (field_type, value) {
+            (FieldType::Array(element_type, _), JsonValue::Array(elements)) => JsonValue::Array(
+                elements
+                    .iter()
+                    .map(|element| {
+                        self.inline_value(element, element_type, schemas, sheets, depth, path)
+                    })
+                    .collect(),
+            ),
+            (FieldType::Struct(members), JsonValue::Object(object)) => {
+                let mut inlined = Map::new();
+                for (member_name, member_type) in members {
+                    if let Some(member) = object.get(member_name) {
+                        let member =
+                            self.inline_value(member, member_type, schemas, sheets, depth, path);
+                        inlined.insert(member_name.clone(), member);
+                    }
+                }
+                JsonValue::Object(inlined)
+            }
+            (FieldType::Custom(target), _) => {
+                self.inline_reference(target, value, schemas, sheets, depth, path)
+            }
+            _ => value.clone(),
+        }
+    }
+
+    /// Resolve a reference ID into the whitelisted fields of the target row.
+    ///
+    /// The ID is kept when the depth is exhausted, the target is not whitelisted,
+    /// the target is already being inlined on the current path (a cycle), the ID is
+    /// the `0` "no reference" sentinel (even if the target has a row 0) or no row
+    /// matches it.
+    fn inline_reference(
+        &self,
+        target: &str,
+        id: &JsonValue,
+        schemas: &SchemaMap,
+        sheets: &ExtractedSheets,
+        depth: usize,
+        path: &mut Vec<String>,
+    ) -> JsonValue {
+        let fields = match self.inline_fields.get(target) {
+            Some(fields) if depth > 0 && !path.iter().any(|sheet| sheet == target) => fields,
+            _ => return id.clone(),
+        };
+        let key = id.to_string();
+        if key == ZERO_SENTINEL {
+            return id.clone();
+        }
+        let (Some(schema), Some(row)) = (schemas.get(target), sheets.find(target, &key)) else {
+            return id.clone();
+        };
+
+        path.push(target.to_string());
+        let mut object = Map::new();
+        for field in fields {
+            let Some(schema_field) = schema.fields.iter().find(|f| &f.name == field) else {
+                continue;
+            };
+            let value = self.inline_value(
+                &row[field],
+                &schema_field.field_type,
+                schemas,
+                sheets,
+                depth - 1,
+                path,
+            );
+            object.insert(field.clone(), value);
+        }
+        path.pop();
+
+        JsonValue::Object(object)
+    }
+
+    fn extract_row(
+        &self,
+        schema: &Schema,
+        record: &csv::StringRecord,
+    ) -> Result<JsonValue, SchemaError> {
+        if record.len() != schema.column_count() {
+            return Err(SchemaError::ColumnCountMismatch {
+                sheet: schema.name.clone(),
+                row_key: record.get(0).unwrap_or_default().to_string(),
+                expected: schema.column_count(),
+                found: record.len(),
+            });
+        }
+
+        let mut object = Map::new();
+
+        for field in &schema.fields {
+            let mut columns = field.columns.iter();
+            let value =
+                self.read_value(schema, record, &field.name, &field.field_type, &mut columns)?;
+            object.insert(field.name.clone(), value);
+        }
+
+        Ok(JsonValue::Object(object))
+    }
+
+    /// Read a value of the given type, consuming one CSV column per scalar element
+    fn read_value(
+        &self,
+        schema: &Schema,
+        record: &csv::StringRecord,
+        column_name: &str,
+        field_type: &FieldType,
+        columns: &mut std::slice::Iter<usize>,
+    ) -> Result<JsonValue, SchemaError> {
+        if let FieldType::Array(element_type, length) = field_type {
+            return (0..*length)
+                .map(|i| {
+                    let element_name = format!("{}[{}]", column_name, i);
+                    self.read_value(schema, record, &element_name, element_type, columns)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(JsonValue::Array);
+        }
+
+        if let FieldType::Struct(members) = field_type {
+            let mut object = Map::new();
+            for (member_name, member_type) in members {
+                let member_column = format!("{}.{}", column_name, member_name);
+                let value =
+                    self.read_value(schema, record, &member_column, member_type, columns)?;
+                object.insert(member_name.clone(), value);
+            }
+            return Ok(JsonValue::Object(object));
+        }
+
+        let raw = columns
+            .next()
+            .and_then(|column| record.get(*column))
+            .unwrap_or_default();
+        let invalid_value = |reason| SchemaError::InvalidValue {
+            sheet: schema.name.clone(),
+            row_key: record.get(0).unwrap_or_default().to_string(),
+            column: column_name.to_string(),
+            value: raw.to_string(),
+            reason,
+        };
+        let value = field_type.parse_value(raw).map_err(invalid_value)?;
+
+        Ok(value.to_json(self.int64_representation))
+    }
+}
+
+impl Default for DataExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn create_test_schema() -> Schema {
+        Schema {
+            name: "TestItem".to_string(),
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    field_type: FieldType::Int32,
+                    columns: vec![0],
+                },
+                Field {
+                    name: "name".to_string(),
+                    field_type: FieldType::String,
+                    columns: vec![1],
+                },
+                Field {
+                    name: "level".to_string(),
+                    field_type: FieldType::Byte,
+                    columns: vec![2],
+                },
+                Field {
+                    name: "isUnique".to_string(),
+                    field_type: FieldType::Bit(1),
+                    columns: vec![3],
+                },
+                Field {
+                    name: "category".to_string(),
+                    field_type: FieldType::Custom("ItemCategory".to_string()),
+                    columns: vec![4],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_extract_rows() {
+        let exporter = DataExporter::new();
+        let schema = create_test_schema();
+        let records = vec![
+            csv::StringRecord::from(vec!["1", "Sword", "10", "True", "3"]),
+            csv::StringRecord::from(vec!["2", "", "0", "False", "0"]),
+        ];
+
+        let rows = exporter.extract_rows(&schema, &records).unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                json!({ "id": 1, "name": "Sword", "level": 10, "isUnique": true, "category": 3 }),
+                json!({ "id": 2, "name": "", "level": 0, "isUnique": false, "category": 0 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rows_are_converted_lazily() {
+        let exporter = DataExporter::new();
+        let schema = create_test_schema();
+        let records = vec![
+            csv::StringRecord::from(vec!["1", "Sword", "10", "True", "3"]),
+            csv::StringRecord::from(vec!["2", "Shield", "x", "False", "0"]),
+        ];
+
+        let mut rows = exporter.rows(&schema, &records);
+
+        assert_eq!(rows.next().unwrap().unwrap()["name"], json!("Sword"));
+        assert!(matches!(
+            rows.next(),
+            Some(Err(SchemaError::InvalidValue { .. }))
+        ));
+        assert!(rows.next().is_none());
+    }
+
+    #[test]
+    fn test_extracted_keys_follow_field_order() {
+        let exporter = DataExporter::new();
+        let schema = create_test_schema();
+        let records = vec![csv::StringRecord::from(vec![
+            "1", "Sword", "10", "True", "3",
+        ])];
+
+        let rows = exporter.extract_rows(&schema, &records).unwrap();
+        let keys: Vec<&String> = rows[0].as_object().unwrap().keys().collect();
+
+        assert_eq!(keys, vec!["id", "name", "level", "isUnique", "category"]);
+    }
+
+    #[test]
+    fn test_extract_array_fields() {
+        let exporter = DataExporter::new();
+        let schema = Schema {
+            name: "Indexed".to_string(),
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    field_type: FieldType::Int32,
+                    columns: vec![0],
+                },
+                Field {
+                    name: "param".to_string(),
+                    field_type: FieldType::Array(Box::new(FieldType::Byte), 2),
+                    columns: vec![1, 3],
+                },
+                Field {
+                    name: "paramValue".to_string(),
+                    field_type: FieldType::Array(Box::new(FieldType::Int16), 2),
+                    columns: vec![2, 4],
+                },
+            ],
+        };
+        let records = vec![csv::StringRecord::from(vec!["1", "1", "10", "2", "-5"])];
+
+        let rows = exporter.extract_rows(&schema, &records).unwrap();
+        assert_eq!(
+            rows,
+            vec![json!({ "id": 1, "param": [1, 2], "paramValue": [10, -5] })]
+        );
+
+        let records = vec![csv::StringRecord::from(vec!["1", "1", "10", "300", "-5"])];
+        match exporter.extract_rows(&schema, &records) {
+            Err(SchemaError::InvalidValue { column, .. }) => assert_eq!(column, "param[1]"),
+            other => panic!("Expected InvalidValue error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_extract_array_of_records() {
+        let exporter = DataExporter::new();
+        let schema = Schema {
+            name: "Zipped".to_string(),
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    field_type: FieldType::Int32,
+                    columns: vec![0],
+                },
+                Field {
+                    name: "baseParam".to_string(),
+                    field_type: FieldType::Array(
+                        Box::new(FieldType::Struct(vec![
+                            (
+                                "param".to_string(),
+                                FieldType::Custom("BaseParam".to_string()),
+                            ),
+                            ("value".to_string(), FieldType::Int16),
+                        ])),
+                        2,
+                    ),
+                    columns: vec![1, 2, 3, 4],
+                },
+            ],
+        };
+        let records = vec![csv::StringRecord::from(vec!["1", "12", "5", "0", "abc"])];
+
+        match exporter.extract_rows(&schema, &records) {
+            Err(SchemaError::InvalidValue { column, .. }) => {
+                assert_eq!(column, "baseParam[1].value")
+            }
+            other => panic!("Expected InvalidValue error, got {:?}", other),
+        }
+
+        let records = vec![csv::StringRecord::from(vec!["1", "12", "5", "0", "0"])];
+        let rows = exporter.extract_rows(&schema, &records).unwrap();
+        assert_eq!(
+            rows,
+            vec![json!({
+                "id": 1,
+                "baseParam": [{ "param": 12, "value": 5 }, { "param": 0, "value": 0 }]
+            })]
+        );
+    }
+
+    #[test]
+    fn test_invalid_value_reports_context() {
+        let exporter = DataExporter::new();
+        let schema = create_test_schema();
+        let records = vec![csv::StringRecord::from(vec![
+            "7", "Sword", "300", "True", "3",
+        ])];
+
+        let result = exporter.extract_rows(&schema, &records);
+
+        match result {
+            Err(SchemaError::InvalidValue {
+                sheet,
+                row_key,
+                column,
+                value,
+                ..
+            }) => {
+                assert_eq!(sheet, "TestItem");
+                assert_eq!(row_key, "7");
+                assert_eq!(column, "level");
+                assert_eq!(value, "300");
+            }
+            other => panic!("Expected InvalidValue error, got {:?}", other),
+        }
+    }
+
+    fn field(name: &str, field_type: FieldType, column: usize) -> Field {
+        Field {
+            name: name.to_string(),
+            field_type,
+            columns: vec![column],
+        }
+    }
+
+    /// Item -> ItemUICategory -> ItemUICategory (self) with Item -> Item (repair)
+    fn create_reference_graph() -> (SchemaMap, RowMap) {
+        let custom = |name: &str| FieldType::Custom(name.to_string());
+        let mut schemas = SchemaMap::new();
+        schemas.insert(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: vec![
+                    field("id", FieldType::Int32, 0),
+                    field("name", FieldType::String, 1),
+                    field("itemUICategory", custom("ItemUICategory"), 2),
+                    field("itemRepair", custom("Item"), 3),
+                ],
+            },
+        );
+        schemas.insert(
+            "ItemUICategory".to_string(),
+            Schema {
+                name: "ItemUICategory".to_string(),
+                fields: vec![
+                    field("id", FieldType::Int32, 0),
+                    field("name", FieldType::String, 1),
+                    field("icon", FieldType::Image, 2),
+                    field("parent", custom("ItemUICategory"), 3),
+                    field("orderMinor", FieldType::Byte, 4),
+                ],
+            },
+        );
+
+        let mut rows = RowMap::new();
+        rows.insert(
+            "Item".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["1", "Sword", "3", "1"]),
+                csv::StringRecord::from(vec!["2", "Shield", "9", "0"]),
+            ],
+        );
+        rows.insert(
+            "ItemUICategory".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["1", "Arms", "060101", "1", "0"]),
+                // Keys are matched trimmed, as the reference validator does
+                csv::StringRecord::from(vec![" 3", "Sword", "060102", "1", "2"]),
+            ],
+        );
+
+        (schemas, rows)
+    }
+
+    fn export_sheet(exporter: &DataExporter, sheet: &str) -> Result<JsonValue, SchemaError> {
+        let temp_dir = TempDir::new().unwrap();
+        let (schemas, rows) = create_reference_graph();
+        exporter.export_and_save(&schemas, &rows, temp_dir.path())?;
+
+        let content = fs::read_to_string(temp_dir.path().join(format!("{}.json", sheet)))?;
+        Ok(serde_json::from_str(&content).unwrap())
+    }
+
+    #[test]
+    fn test_inline_whitelisted_references() {
+        let exporter = DataExporter::new().with_inline_fields(
+            "ItemUICategory",
+            vec!["id".to_string(), "name".to_string(), "icon".to_string()],
+        );
+
+        let items = export_sheet(&exporter, "Item").unwrap();
+
+        // Only whitelisted sheets are inlined; the missing row 9 keeps its key
+        assert_eq!(
+            items,
+            json!([
+                {
+                    "id": 1,
+                    "name": "Sword",
+                    "itemUICategory": { "id": 3, "name": "Sword", "icon": "060102" },
+                    "itemRepair": 1
+                },
+                { "id": 2, "name": "Shield", "itemUICategory": 9, "itemRepair": 0 }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_inline_keeps_zero_sentinel() {
+        let temp_dir = TempDir::new().unwrap();
+        let (schemas, mut rows) = create_reference_graph();
+        rows.get_mut("ItemUICategory")
+            .unwrap()
+            .push(csv::StringRecord::from(vec!["0", "", "000000", "0", "0"]));
+        rows.get_mut("Item").unwrap()[1] = csv::StringRecord::from(vec!["2", "Shield", "0", "0"]);
+
+        let exporter = DataExporter::new()
+            .with_inline_fields("ItemUICategory", vec!["id".to_string(), "name".to_string()]);
+        exporter
+            .export_and_save(&schemas, &rows, temp_dir.path())
+            .unwrap();
+        let content = fs::read_to_string(temp_dir.path().join("Item.json")).unwrap();
+        let items: JsonValue = serde_json::from_str(&content).unwrap();
+
+        // Row 0 exists, but 0 still means "no reference"
+        assert_eq!(items[1]["itemUICategory"], json!(0));
+        assert_eq!(
+            items[0]["itemUICategory"],
+            json!({ "id": 3, "name": "Sword" })
+        );
+    }
+
+    #[test]
+    fn test_inline_depth_and_cycles() {
+        let exporter = DataExporter::new()
+            .with_inline_depth(3)
+            .with_inline_fields("Item", vec!["name".to_string(), "itemRepair".to_string()])
+            .with_inline_fields(
+                "ItemUICategory",
+                vec!["name".to_string(), "parent".to_string()],
+            );
+
+        // Item -> Item is cut at the sheet itself, keeping the ID
+        let items = export_sheet(&exporter, "Item").unwrap();
+        assert_eq!(items[0]["itemRepair"], json!(1));
+
+        // ItemUICategory -> ItemUICategory is cut after one level from Item
+        assert_eq!(
+            items[0]["itemUICategory"],
+            json!({ "name": "Sword", "parent": 1 })
+        );
+
+        let exporter = DataExporter::new()
+            .with_inline_depth(0)
+            .with_inline_fields("ItemUICategory", vec!["name".to_string()]);
+        let items = export_sheet(&exporter, "Item").unwrap();
+        assert_eq!(items[0]["itemUICategory"], json!(3));
+    }
+
+    #[test]
+    fn test_inline_unknown_field_is_rejected() {
+        let exporter =
+            DataExporter::new().with_inline_fields("ItemUICategory", vec!["label".to_string()]);
+
+        match export_sheet(&exporter, "Item") {
+            Err(SchemaError::InvalidInlineField { sheet, field, .. }) => {
+                assert_eq!(sheet, "ItemUICategory");
+                assert_eq!(field, "label");
+            }
+            other => panic!("Expected InvalidInlineField error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_column_count_mismatch() {
+        let exporter = DataExporter::new();
+        let schema = create_test_schema();
+        let records = vec![csv::StringRecord::from(vec!["7", "Sword", "10", "True"])];
+
+        match exporter.extract_rows(&schema, &records) {
+            Err(SchemaError::ColumnCountMismatch {
+                row_key,
+                expected,
+                found,
+                ..
+            }) => {
+                assert_eq!(row_key, "7");
+                assert_eq!(expected, 5);
+                assert_eq!(found, 4);
+            }
+            other => panic!("Expected ColumnCountMismatch error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_export_and_save_writes_one_file_per_schema() {
+        let temp_dir = TempDir::new().unwrap();
+        let exporter = DataExporter::new();
+
+        let mut schemas = HashMap::new();
+        schemas.insert("TestItem".to_string(), create_test_schema());
+        schemas.insert(
+            "ItemCategory".to_string(),
+            Schema {
+                name: "ItemCategory".to_string(),
+                fields: vec![
+                    Field {
+                        name: "id".to_string(),
+                        field_type: FieldType::Int32,
+                        columns: vec![0],
+                    },
+                    Field {
+                        name: "name".to_string(),
+                        field_type: FieldType::String,
+                        columns: vec![1],
+                    },
+                ],
+            },
+        );
+
+        let mut rows = RowMap::new();
+        rows.insert(
+            "ItemCategory".to_string(),
+            vec![csv::StringRecord::from(vec!["3", "Weapon"])],
+        );
+
+        let written = exporter
+            .export_and_save(&schemas, &rows, temp_dir.path())
+            .unwrap();
+
+        assert_eq!(
+            written,
+            vec![
+                temp_dir.path().join("ItemCategory.json"),
+                temp_dir.path().join("TestItem.json"),
+            ]
+        );
+
+        let category: JsonValue =
+            serde_json::from_str(&fs::read_to_string(&written[0]).unwrap()).unwrap();
+        assert_eq!(category, json!([{ "id": 3, "name": "Weapon" }]));
+
+        let items: JsonValue =
+            serde_json::from_str(&fs::read_to_string(&written[1]).unwrap()).unwrap();
+        assert_eq!(items, json!([]));
+    }
+}
@@ -0,0 +1,284 @@
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Int64Representation, Schema, SchemaMap};
+
+pub struct EffectSchemaGenerator {
+    int64_representation: Int64Representation,
+}
+
+impl EffectSchemaGenerator {
+    pub fn new() -> Self {
+        Self {
+            int64_representation: Int64Representation::default(),
+        }
+    }
+
+    /// Set how 64-bit integer values are decoded, matching the extracted data
+    pub fn with_int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
+
+    /// Generate Effect `Schema.Struct` definitions for all schemas
+    pub fn generate_effect_schemas(&self, schemas: &SchemaMap) -> String {
+        let mut content = String::new();
+
+        // Add header comment
+        content.push_str("// Generated Effect schemas from CSV schemas\n");
+        content.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+        content.push_str("import { Schema } from \"effect\";\n\n");
+
+        content.push_str("/** Integer within the range of its CSV column type */\n");
+        content.push_str("const int = (minimum: number, maximum: number) =>\n");
+        content
+            .push_str("  Schema.Number.pipe(Schema.int(), Schema.between(minimum, maximum));\n\n");
+
+        // Add special type definitions
+        content.push_str(&self.generate_special_schemas());
+        content.push('\n');
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Branded IDs come first so every struct can reference them
+        content.push_str("// Branded row key schemas referencing each sheet\n");
+        for schema in &sorted_schemas {
+            let id_name = id_schema_name(&schema.name);
+            content.push_str(&format!(
+                "export const {} = int(0, {}).pipe(Schema.brand(\"{}\"));\n",
+                id_name,
+                u32::MAX,
+                schema.name
+            ));
+            content.push_str(&format!(
+                "export type {} = typeof {}.Type;\n",
+                id_name, id_name
+            ));
+        }
+        content.push('\n');
+
+        for schema in sorted_schemas {
+            content.push_str(&self.generate_struct(schema));
+            content.push('\n');
+        }
+
+        content
+    }
+
+    fn generate_special_schemas(&self) -> String {
+        let mut schemas = String::new();
+
+        schemas.push_str("// Special type definitions for game data\n");
+        schemas.push_str("/** Image file path identifier */\n");
+        schemas.push_str(
+            "export const ImagePath = Schema.String.pipe(Schema.pattern(/^[0-9]+$/));\n\n",
+        );
+
+        schemas.push_str("/** Row reference identifier */\n");
+        schemas.push_str(&format!("export const RowId = int(0, {});\n\n", u32::MAX));
+
+        schemas.push_str("/** Key identifier string */\n");
+        schemas.push_str("export const KeyString = Schema.String;\n\n");
+
+        schemas.push_str("/** Color code identifier */\n");
+        schemas.push_str(&format!("export const ColorCode = int(0, {});\n", u32::MAX));
+
+        schemas
+    }
+
+    fn generate_struct(&self, schema: &Schema) -> String {
+        let mut definition = String::new();
+
+        definition.push_str(&format!(
+            "export const {} = Schema.Struct({{\n",
+            schema.name
+        ));
+        for field in &schema.fields {
+            definition.push_str(&format!(
+                "  {}: {},\n",
+                field.name,
+                self.field_type_to_effect(&field.field_type)
+            ));
+        }
+        definition.push_str("});\n");
+        definition.push_str(&format!(
+            "export type {} = typeof {}.Type;\n",
+            schema.name, schema.name
+        ));
+
+        definition
+    }
+
+    /// Convert FieldType to an Effect schema expression
+    fn field_type_to_effect(&self, field_type: &FieldType) -> String {
+        match field_type {
+            FieldType::String => "Schema.String".to_string(),
+            FieldType::Int64 => self.int64_to_effect(i64::MIN.into(), i64::MAX.into()),
+            FieldType::Uint64 => self.int64_to_effect(0, u64::MAX.into()),
+            FieldType::Float => "Schema.Number".to_string(),
+            FieldType::Bool | FieldType::Bit(_) => "Schema.Boolean".to_string(),
+            FieldType::Image => "ImagePath".to_string(),
+            FieldType::Row => "RowId".to_string(),
+            FieldType::Key => "KeyString".to_string(),
+            FieldType::Color => "ColorCode".to_string(),
+            FieldType::Custom(type_name) => id_schema_name(type_name),
+            FieldType::Array(element_type, length) => format!(
+                "Schema.Array({}).pipe(Schema.itemsCount({}))",
+                self.field_type_to_effect(element_type),
+                length
+            ),
+            FieldType::Struct(members) => {
+                let members: Vec<String> = members
+                    .iter()
+                    .map(|(name, member_type)| {
+                        format!("{}: {}", name, self.field_type_to_effect(member_type))
+                    })
+                    .collect();
+                format!("Schema.Struct({{ {} }})", members.join(", "))
+            }
+            _ => match field_type.integer_bounds() {
+                Some((minimum, maximum)) => format!("int({}, {})", minimum, maximum),
+                None => "Schema.Unknown".to_string(),
+            },
+        }
+    }
+
+    fn int64_to_effect(&self, minimum: i128, maximum: i128) -> String {
+        match self.int64_representation {
+            // Decimal strings in the data decode to bigint
+            Int64Representation::BigInt => format!(
+                "Schema.BigInt.pipe(Schema.betweenBigInt({}n, {}n))",
+                minimum, maximum
+            ),
+            Int64Representation::String if minimum < 0 => {
+                "Schema.String.pipe(Schema.pattern(/^-?[0-9]+$/))".to_string()
+            }
+            Int64Representation::String => {
+                "Schema.String.pipe(Schema.pattern(/^[0-9]+$/))".to_string()
+            }
+            Int64Representation::Number => "Schema.Number.pipe(Schema.int())".to_string(),
+            Int64Representation::Quad => {
+                let part = format!("int(0, {})", u16::MAX);
+                format!("Schema.Tuple({})", vec![part; 4].join(", "))
+            }
+        }
+    }
+}
+
+impl SchemaGenerator for EffectSchemaGenerator {
+    fn name(&self) -> &'static str {
+        "effect"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "effect.ts"
+    }
+
+    fn supports_inlined_data(&self) -> bool {
+        false
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_effect_schemas(schemas)
+    }
+}
+
+impl Default for EffectSchemaGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn id_schema_name(schema_name: &str) -> String {
+    format!("{}Id", schema_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn create_test_schemas() -> SchemaMap {
+        let fields = vec![
+            ("id", FieldType::Int32),
+            ("name", FieldType::String),
+            ("level", FieldType::Byte),
+            ("isUnique", FieldType::Bit(1)),
+            ("icon", FieldType::Image),
+            ("category", FieldType::Custom("ItemCategory".to_string())),
+            (
+                "baseParam",
+                FieldType::Array(
+                    Box::new(FieldType::Struct(vec![
+                        (
+                            "param".to_string(),
+                            FieldType::Custom("BaseParam".to_string()),
+                        ),
+                        ("value".to_string(), FieldType::Int16),
+                    ])),
+                    2,
+                ),
+            ),
+            ("model", FieldType::Uint64),
+        ];
+
+        SchemaMap::from([(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(column, (name, field_type))| Field {
+                        name: name.to_string(),
+                        field_type,
+                        columns: vec![column],
+                    })
+                    .collect(),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_generate_struct() {
+        let generator = EffectSchemaGenerator::new();
+        let schemas = create_test_schemas();
+        let effect = generator.generate_effect_schemas(&schemas);
+
+        assert!(effect.contains("import { Schema } from \"effect\";\n"));
+        assert!(effect.contains(
+            "export const ItemId = int(0, 4294967295).pipe(Schema.brand(\"Item\"));\nexport type ItemId = typeof ItemId.Type;\n"
+        ));
+        assert!(effect.contains(
+            r#"export const Item = Schema.Struct({
+  id: int(-2147483648, 2147483647),
+  name: Schema.String,
+  level: int(0, 255),
+  isUnique: Schema.Boolean,
+  icon: ImagePath,
+  category: ItemCategoryId,
+  baseParam: Schema.Array(Schema.Struct({ param: BaseParamId, value: int(-32768, 32767) })).pipe(Schema.itemsCount(2)),
+  model: Schema.BigInt.pipe(Schema.betweenBigInt(0n, 18446744073709551615n)),
+});
+export type Item = typeof Item.Type;
+"#
+        ));
+    }
+
+    #[test]
+    fn test_int64_follows_representation() {
+        let generator =
+            EffectSchemaGenerator::new().with_int64_representation(Int64Representation::String);
+        assert_eq!(
+            generator.field_type_to_effect(&FieldType::Int64),
+            "Schema.String.pipe(Schema.pattern(/^-?[0-9]+$/))"
+        );
+
+        let generator =
+            EffectSchemaGenerator::new().with_int64_representation(Int64Representation::Quad);
+        assert_eq!(
+            generator.field_type_to_effect(&FieldType::Uint64),
+            "Schema.Tuple(int(0, 65535), int(0, 65535), int(0, 65535), int(0, 65535))"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use serde_json::{Value as JsonValue, json};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -11,15 +12,222 @@ pub enum SchemaError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite error: {0}")]
+    SqliteError(#[from] rusqlite::Error),
+
     #[error("Invalid CSV format: {reason}")]
     InvalidFormat { reason: String },
 
     #[error("Invalid bit value format: {input}")]
     InvalidBitValue { input: String },
 
+    #[error("Unknown type {token:?} at column {column} in file: {path}")]
+    UnknownType {
+        token: String,
+        column: usize,
+        path: String,
+    },
+
+    #[error("Invalid value {value:?} in {sheet} (row: {row_key}, column: {column}): {reason}")]
+    InvalidValue {
+        sheet: String,
+        row_key: String,
+        column: String,
+        value: String,
+        reason: String,
+    },
+
+    #[error(
+        "Column count mismatch in {sheet} (row: {row_key}): expected {expected}, found {found}"
+    )]
+    ColumnCountMismatch {
+        sheet: String,
+        row_key: String,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Invalid bit mask bit&{mask:02X} at column {column} in file: {path} ({reason})")]
+    BitMaskConflict {
+        mask: u8,
+        column: usize,
+        path: String,
+        reason: String,
+    },
+
+    #[error("Cannot inline {sheet}.{field}: {reason}")]
+    InvalidInlineField {
+        sheet: String,
+        field: String,
+        reason: String,
+    },
+
+    #[error(
+        "The {format} output does not describe data written with --inline; generate it without --inline"
+    )]
+    InlineFormatConflict { format: String },
+
+    #[error("Unknown output format: {format} (available: {available})")]
+    UnknownFormat { format: String, available: String },
+
+    #[error("Multiple output formats would write to the same file: {path}")]
+    OutputPathConflict { path: String },
+
     #[error("Required CSV header not found: {header} in file: {path}")]
     MissingCsvHeader { header: String, path: String },
 
     #[error("Invalid CSV structure: found multiple {header} rows in file: {path}")]
     DuplicateCsvHeader { header: String, path: String },
+
+    #[error("{} errors while building schemas", errors.len())]
+    Multiple { errors: Vec<SchemaError> },
+}
+
+impl SchemaError {
+    /// Stable kebab-case name of the error variant
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SchemaError::FileNotFound { .. } => "file-not-found",
+            SchemaError::CsvError(_) => "csv",
+            SchemaError::IoError(_) => "io",
+            #[cfg(feature = "sqlite")]
+            SchemaError::SqliteError(_) => "sqlite",
+            SchemaError::InvalidFormat { .. } => "invalid-format",
+            SchemaError::InvalidBitValue { .. } => "invalid-bit-value",
+            SchemaError::UnknownType { .. } => "unknown-type",
+            SchemaError::InvalidValue { .. } => "invalid-value",
+            SchemaError::ColumnCountMismatch { .. } => "column-count-mismatch",
+            SchemaError::BitMaskConflict { .. } => "bit-mask-conflict",
+            SchemaError::InvalidInlineField { .. } => "invalid-inline-field",
+            SchemaError::InlineFormatConflict { .. } => "inline-format-conflict",
+            SchemaError::UnknownFormat { .. } => "unknown-format",
+            SchemaError::OutputPathConflict { .. } => "output-path-conflict",
+            SchemaError::MissingCsvHeader { .. } => "missing-csv-header",
+            SchemaError::DuplicateCsvHeader { .. } => "duplicate-csv-header",
+            SchemaError::Multiple { .. } => "multiple",
+        }
+    }
+
+    /// The error's kind and structured fields as a JSON object (the message is `to_string`)
+    pub fn to_json(&self) -> JsonValue {
+        let fields = match self {
+            SchemaError::FileNotFound { path, source_file } => {
+                json!({ "path": path, "source_file": source_file })
+            }
+            SchemaError::CsvError(error) => {
+                json!({ "line": error.position().map(|position| position.line()) })
+            }
+            SchemaError::IoError(error) => json!({ "io_kind": format!("{:?}", error.kind()) }),
+            #[cfg(feature = "sqlite")]
+            SchemaError::SqliteError(_) => json!({}),
+            SchemaError::InvalidFormat { reason } => json!({ "reason": reason }),
+            SchemaError::InvalidBitValue { input } => json!({ "input": input }),
+            SchemaError::UnknownType {
+                token,
+                column,
+                path,
+            } => json!({ "token": token, "column": column, "path": path }),
+            SchemaError::InvalidValue {
+                sheet,
+                row_key,
+                column,
+                value,
+                reason,
+            } => json!({
+                "sheet": sheet,
+                "row_key": row_key,
+                "column": column,
+                "value": value,
+                "reason": reason,
+            }),
+            SchemaError::ColumnCountMismatch {
+                sheet,
+                row_key,
+                expected,
+                found,
+            } => json!({
+                "sheet": sheet,
+                "row_key": row_key,
+                "expected": expected,
+                "found": found,
+            }),
+            SchemaError::BitMaskConflict {
+                mask,
+                column,
+                path,
+                reason,
+            } => json!({ "mask": mask, "column": column, "path": path, "reason": reason }),
+            SchemaError::InvalidInlineField {
+                sheet,
+                field,
+                reason,
+            } => json!({ "sheet": sheet, "field": field, "reason": reason }),
+            SchemaError::InlineFormatConflict { format } => json!({ "format": format }),
+            SchemaError::UnknownFormat { format, available } => {
+                json!({ "format": format, "available": available })
+            }
+            SchemaError::OutputPathConflict { path } => json!({ "path": path }),
+            SchemaError::MissingCsvHeader { header, path }
+            | SchemaError::DuplicateCsvHeader { header, path } => {
+                json!({ "header": header, "path": path })
+            }
+            SchemaError::Multiple { errors } => {
+                let errors: Vec<JsonValue> = errors.iter().flat_map(Self::to_json_list).collect();
+                json!({ "errors": errors })
+            }
+        };
+
+        let mut object = json!({ "kind": self.kind() });
+        if let (Some(object), JsonValue::Object(fields)) = (object.as_object_mut(), fields) {
+            object.extend(fields);
+        }
+        object
+    }
+
+    /// Each error as a JSON object with its message, kind and fields, expanding `Multiple`
+    pub fn to_json_list(&self) -> Vec<JsonValue> {
+        match self {
+            SchemaError::Multiple { errors } => {
+                errors.iter().flat_map(Self::to_json_list).collect()
+            }
+            error => {
+                let mut entry = json!({ "message": error.to_string() });
+                if let (Some(entry), JsonValue::Object(fields)) =
+                    (entry.as_object_mut(), error.to_json())
+                {
+                    entry.extend(fields);
+                }
+                vec![entry]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let error = SchemaError::FileNotFound {
+            path: "fixtures/ItemLevel.csv".to_string(),
+            source_file: "fixtures/Item.csv".to_string(),
+        };
+        assert_eq!(
+            error.to_json(),
+            json!({
+                "kind": "file-not-found",
+                "path": "fixtures/ItemLevel.csv",
+                "source_file": "fixtures/Item.csv",
+            })
+        );
+
+        let error = SchemaError::MissingCsvHeader {
+            header: "key".to_string(),
+            path: "Item.csv".to_string(),
+        };
+        assert_eq!(error.kind(), "missing-csv-header");
+        assert_eq!(error.to_json()["header"], "key");
+    }
 }
@@ -0,0 +1,318 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::effect::EffectSchemaGenerator;
+use super::error::SchemaError;
+use super::graphql::GraphqlGenerator;
+use super::json_schema::JsonSchemaGenerator;
+use super::proto::ProtoGenerator;
+use super::rust::RustGenerator;
+use super::sql::{SqlDdlGenerator, SqlDialect};
+use super::types::{Int64Representation, SchemaMap};
+use super::typescript::TypeScriptGenerator;
+use super::zod::ZodGenerator;
+
+/// Output format that turns the built schemas into a single text artifact
+pub trait SchemaGenerator {
+    /// Format name used to select the generator (e.g. `ts`)
+    fn name(&self) -> &'static str;
+
+    /// Other names accepted for this format
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Extension of the generated file, without the leading dot
+    fn file_extension(&self) -> &'static str;
+
+    /// Generate the artifact for all schemas
+    fn generate(&self, schemas: &SchemaMap) -> String;
+
+    /// Whether the artifact still matches data files written with `--inline`.
+    ///
+    /// Formats describing the extracted rows with raw reference keys return false.
+    fn supports_inlined_data(&self) -> bool {
+        true
+    }
+
+    /// Generate the artifact for all schemas and save to file
+    fn generate_and_save(
+        &self,
+        schemas: &SchemaMap,
+        output_path: &Path,
+    ) -> Result<(), SchemaError> {
+        // Create parent directories if they don't exist
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(output_path, self.generate(schemas))?;
+        Ok(())
+    }
+}
+
+/// Set of available generators, looked up by format name
+pub struct GeneratorRegistry {
+    generators: Vec<Box<dyn SchemaGenerator>>,
+}
+
+impl GeneratorRegistry {
+    pub fn new() -> Self {
+        Self {
+            generators: Vec::new(),
+        }
+    }
+
+    /// Register a generator, replacing a registered one with the same name in place
+    pub fn with_generator(mut self, generator: impl SchemaGenerator + 'static) -> Self {
+        match self
+            .generators
+            .iter_mut()
+            .find(|registered| registered.name() == generator.name())
+        {
+            Some(registered) => *registered = Box::new(generator),
+            None => self.generators.push(Box::new(generator)),
+        }
+        self
+    }
+
+    /// Register every built-in output format, in the order listed by `--format`
+    pub fn with_builtin_generators(
+        self,
+        int64_representation: Int64Representation,
+        sql_dialect: SqlDialect,
+    ) -> Self {
+        self.with_generator(
+            TypeScriptGenerator::new().with_int64_representation(int64_representation),
+        )
+        .with_generator(SqlDdlGenerator::new().with_dialect(sql_dialect))
+        .with_generator(JsonSchemaGenerator::new().with_int64_representation(int64_representation))
+        .with_generator(
+            EffectSchemaGenerator::new().with_int64_representation(int64_representation),
+        )
+        .with_generator(ZodGenerator::new().with_int64_representation(int64_representation))
+        .with_generator(RustGenerator::new().with_int64_representation(int64_representation))
+        .with_generator(ProtoGenerator::new())
+        .with_generator(GraphqlGenerator::new())
+    }
+
+    /// Find a generator by its name or one of its aliases
+    pub fn get(&self, format: &str) -> Result<&dyn SchemaGenerator, SchemaError> {
+        self.generators
+            .iter()
+            .find(|generator| generator.name() == format || generator.aliases().contains(&format))
+            .map(Box::as_ref)
+            .ok_or_else(|| SchemaError::UnknownFormat {
+                format: format.to_string(),
+                available: self.names().join(", "),
+            })
+    }
+
+    /// Resolve requested formats, each with an optional explicit path, to output files.
+    ///
+    /// A single format without a path writes to `default_path`; several formats share
+    /// its file stem with their own extensions. Two outputs may not share a path.
+    pub fn resolve_outputs(
+        &self,
+        requests: &[(String, Option<PathBuf>)],
+        default_path: &Path,
+    ) -> Result<Vec<(&dyn SchemaGenerator, PathBuf)>, SchemaError> {
+        let mut seen = HashSet::new();
+        let mut outputs = Vec::new();
+
+        for (format, path) in requests {
+            let generator = self.get(format)?;
+            let path = match path {
+                Some(path) => path.clone(),
+                None if requests.len() == 1 => default_path.to_path_buf(),
+                None => default_path.with_extension(generator.file_extension()),
+            };
+
+            if !seen.insert(path.clone()) {
+                return Err(SchemaError::OutputPathConflict {
+                    path: path.to_string_lossy().to_string(),
+                });
+            }
+            outputs.push((generator, path));
+        }
+
+        Ok(outputs)
+    }
+
+    /// Names of all registered formats in registration order
+    pub fn names(&self) -> Vec<&'static str> {
+        self.generators
+            .iter()
+            .map(|generator| generator.name())
+            .collect()
+    }
+}
+
+impl Default for GeneratorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Schema;
+    use tempfile::TempDir;
+
+    struct NameList;
+
+    impl SchemaGenerator for NameList {
+        fn name(&self) -> &'static str {
+            "names"
+        }
+
+        fn aliases(&self) -> &'static [&'static str] {
+            &["list"]
+        }
+
+        fn file_extension(&self) -> &'static str {
+            "txt"
+        }
+
+        fn generate(&self, schemas: &SchemaMap) -> String {
+            let mut names: Vec<&String> = schemas.keys().collect();
+            names.sort();
+            names.iter().map(|name| format!("{}\n", name)).collect()
+        }
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let registry = GeneratorRegistry::new().with_generator(NameList);
+
+        assert_eq!(registry.names(), vec!["names"]);
+        assert_eq!(registry.get("names").unwrap().file_extension(), "txt");
+        assert_eq!(registry.get("list").unwrap().name(), "names");
+
+        match registry.get("yaml") {
+            Err(SchemaError::UnknownFormat { format, available }) => {
+                assert_eq!(format, "yaml");
+                assert_eq!(available, "names");
+            }
+            other => panic!(
+                "Expected UnknownFormat error, got {:?}",
+                other.map(|g| g.name())
+            ),
+        }
+    }
+
+    #[test]
+    fn test_builtin_generators() {
+        let registry = GeneratorRegistry::new()
+            .with_builtin_generators(Int64Representation::default(), SqlDialect::default());
+
+        assert_eq!(
+            registry.names(),
+            vec![
+                "ts",
+                "sql",
+                "json-schema",
+                "effect",
+                "zod",
+                "rust",
+                "proto",
+                "graphql"
+            ]
+        );
+        assert_eq!(registry.get("typescript").unwrap().name(), "ts");
+    }
+
+    #[test]
+    fn test_generator_replaced_by_name() {
+        struct NameMarkdown;
+
+        impl SchemaGenerator for NameMarkdown {
+            fn name(&self) -> &'static str {
+                "names"
+            }
+
+            fn file_extension(&self) -> &'static str {
+                "md"
+            }
+
+            fn generate(&self, _schemas: &SchemaMap) -> String {
+                String::new()
+            }
+        }
+
+        let registry = GeneratorRegistry::new()
+            .with_generator(NameList)
+            .with_builtin_generators(Int64Representation::default(), SqlDialect::default())
+            .with_generator(NameMarkdown);
+
+        // The replacement keeps the position of the generator it replaces
+        assert_eq!(registry.names().len(), 9);
+        assert_eq!(registry.names()[0], "names");
+        assert_eq!(registry.get("names").unwrap().file_extension(), "md");
+    }
+
+    #[test]
+    fn test_resolve_outputs() {
+        let registry = GeneratorRegistry::new().with_generator(NameList);
+        let default_path = Path::new("out/schemas.ts");
+        let paths = |requests: &[(String, Option<PathBuf>)]| {
+            registry
+                .resolve_outputs(requests, default_path)
+                .map(|outputs| {
+                    outputs
+                        .into_iter()
+                        .map(|(_, path)| path)
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        // A single format keeps the default path as-is
+        assert_eq!(
+            paths(&[("names".to_string(), None)]).unwrap(),
+            vec![PathBuf::from("out/schemas.ts")]
+        );
+
+        // Several formats use the default stem or their explicit paths
+        assert_eq!(
+            paths(&[
+                ("names".to_string(), None),
+                ("list".to_string(), Some(PathBuf::from("docs/names.md"))),
+            ])
+            .unwrap(),
+            vec![
+                PathBuf::from("out/schemas.txt"),
+                PathBuf::from("docs/names.md")
+            ]
+        );
+
+        match paths(&[("names".to_string(), None), ("list".to_string(), None)]) {
+            Err(SchemaError::OutputPathConflict { path }) => assert_eq!(path, "out/schemas.txt"),
+            other => panic!("Expected OutputPathConflict error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_generate_and_save_creates_parent_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("nested/names.txt");
+        let schemas: SchemaMap = ["Item", "BaseParam"]
+            .into_iter()
+            .map(|name| {
+                let schema = Schema {
+                    name: name.to_string(),
+                    fields: vec![],
+                };
+                (name.to_string(), schema)
+            })
+            .collect();
+
+        NameList.generate_and_save(&schemas, &output_path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&output_path).unwrap(),
+            "BaseParam\nItem\n"
+        );
+    }
+}
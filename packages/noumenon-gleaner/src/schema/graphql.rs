@@ -0,0 +1,352 @@
+use std::collections::HashSet;
+
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Schema, SchemaMap};
+use super::utils::{record_members, record_type_name, to_camel_case};
+
+/// Scalars for values that do not fit GraphQL's signed 32-bit `Int`
+const CUSTOM_SCALARS: &[(&str, &str)] = &[
+    ("UInt32", "Unsigned 32-bit integer"),
+    (
+        "Int64",
+        "Signed 64-bit integer, serialized as in the extracted data",
+    ),
+    (
+        "UInt64",
+        "Unsigned 64-bit integer, serialized as in the extracted data",
+    ),
+];
+
+/// Built-in scalars and root types that record types must not shadow
+const RESERVED_TYPE_NAMES: &[&str] = &["Int", "Float", "String", "Boolean", "ID", "Query"];
+
+pub struct GraphqlGenerator;
+
+/// Object type collected while generating, emitted after its parent
+struct GraphqlType<'a> {
+    name: String,
+    description: String,
+    members: Vec<(&'a str, &'a FieldType)>,
+}
+
+impl GraphqlGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate a GraphQL SDL document with one object type per sheet and a `Query` type
+    pub fn generate_graphql_schema(&self, schemas: &SchemaMap) -> String {
+        let mut content = String::new();
+
+        // Add header comment
+        content.push_str("# Generated GraphQL schema from CSV schemas\n");
+        content.push_str("# This file is auto-generated. Do not edit manually.\n");
+
+        for (name, description) in CUSTOM_SCALARS {
+            content.push_str(&format!("\n\"\"\"{}\"\"\"\nscalar {}\n", description, name));
+        }
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Record type names must not collide with any other emitted type
+        let mut taken: HashSet<String> = RESERVED_TYPE_NAMES
+            .iter()
+            .chain(CUSTOM_SCALARS.iter().map(|(name, _)| name))
+            .map(|name| name.to_string())
+            .chain(schemas.keys().cloned())
+            .collect();
+
+        for schema in &sorted_schemas {
+            let mut pending = vec![GraphqlType {
+                name: schema.name.clone(),
+                description: format!("Row of the {} sheet", schema.name),
+                members: schema
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.as_str(), &field.field_type))
+                    .collect(),
+            }];
+
+            // Record types are emitted right after the type that introduced them
+            let mut index = 0;
+            while index < pending.len() {
+                let mut nested = Vec::new();
+                let definition =
+                    self.generate_object(schemas, &mut taken, &pending[index], &mut nested);
+                pending.extend(nested);
+                content.push('\n');
+                content.push_str(&definition);
+                index += 1;
+            }
+        }
+
+        content.push('\n');
+        content.push_str(&self.generate_query(&sorted_schemas));
+
+        content
+    }
+
+    fn generate_object<'a>(
+        &self,
+        schemas: &SchemaMap,
+        taken: &mut HashSet<String>,
+        definition: &GraphqlType<'a>,
+        nested: &mut Vec<GraphqlType<'a>>,
+    ) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!("\"\"\"{}\"\"\"\n", definition.description));
+        output.push_str(&format!("type {} {{\n", definition.name));
+
+        for (name, field_type) in &definition.members {
+            let mut nested_name = String::new();
+            if let Some(members) = record_members(field_type) {
+                nested_name = record_type_name(&definition.name, name, taken);
+                nested.push(GraphqlType {
+                    name: nested_name.clone(),
+                    description: format!("Record in {}.{}", definition.name, name),
+                    members: members
+                        .iter()
+                        .map(|(member_name, member_type)| (member_name.as_str(), member_type))
+                        .collect(),
+                });
+            }
+
+            match reference_target(field_type) {
+                // Loaded sheets resolve to their rows, with the raw key alongside
+                Some(target) if schemas.contains_key(target) => {
+                    let suffix = if matches!(field_type, FieldType::Array(..)) {
+                        "Ids"
+                    } else {
+                        "Id"
+                    };
+                    output.push_str(&format!(
+                        "  {}: {}\n",
+                        name,
+                        field_type_to_graphql(field_type, &nested_name, true)
+                    ));
+                    output.push_str(&format!(
+                        "  {}{}: {}\n",
+                        name,
+                        suffix,
+                        field_type_to_graphql(field_type, &nested_name, false)
+                    ));
+                }
+                _ => output.push_str(&format!(
+                    "  {}: {}\n",
+                    name,
+                    field_type_to_graphql(field_type, &nested_name, false)
+                )),
+            }
+        }
+        output.push_str("}\n");
+
+        output
+    }
+
+    fn generate_query(&self, schemas: &[&Schema]) -> String {
+        let mut query = String::new();
+
+        query.push_str("type Query {\n");
+        for schema in schemas {
+            let field_name = to_camel_case(&schema.name);
+            let key_type = schema
+                .fields
+                .iter()
+                .find(|field| field.columns.first() == Some(&0))
+                .map_or("UInt32!".to_string(), |field| {
+                    field_type_to_graphql(&field.field_type, &schema.name, false)
+                });
+
+            query.push_str(&format!(
+                "  \"\"\"Look up a row of the {} sheet by its key\"\"\"\n",
+                schema.name
+            ));
+            query.push_str(&format!(
+                "  {}ByKey(key: {}): {}\n",
+                field_name, key_type, schema.name
+            ));
+            query.push_str(&format!(
+                "  \"\"\"All rows of the {} sheet\"\"\"\n",
+                schema.name
+            ));
+            query.push_str(&format!("  {}List: [{}!]!\n", field_name, schema.name));
+        }
+        query.push_str("}\n");
+
+        query
+    }
+}
+
+impl SchemaGenerator for GraphqlGenerator {
+    fn name(&self) -> &'static str {
+        "graphql"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["gql"]
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "graphql"
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_graphql_schema(schemas)
+    }
+}
+
+impl Default for GraphqlGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convert FieldType to a GraphQL type reference.
+///
+/// With `resolve_references`, `Custom` fields become their (nullable) target type
+/// instead of the raw `UInt32!` key; `nested_name` names a record type.
+fn field_type_to_graphql(
+    field_type: &FieldType,
+    nested_name: &str,
+    resolve_references: bool,
+) -> String {
+    match field_type {
+        FieldType::String | FieldType::Key | FieldType::Image => "String!".to_string(),
+        FieldType::Int32
+        | FieldType::Int16
+        | FieldType::Uint16
+        | FieldType::Byte
+        | FieldType::SByte => "Int!".to_string(),
+        FieldType::Uint32 | FieldType::Row | FieldType::Color => "UInt32!".to_string(),
+        FieldType::Int64 => "Int64!".to_string(),
+        FieldType::Uint64 => "UInt64!".to_string(),
+        FieldType::Float => "Float!".to_string(),
+        FieldType::Bool | FieldType::Bit(_) => "Boolean!".to_string(),
+        // Referenced rows may be missing, so resolved references are nullable
+        FieldType::Custom(type_name) if resolve_references => type_name.clone(),
+        FieldType::Custom(_) => "UInt32!".to_string(),
+        FieldType::Array(element_type, _) => format!(
+            "[{}]!",
+            field_type_to_graphql(element_type, nested_name, resolve_references)
+        ),
+        FieldType::Struct(_) => format!("{}!", nested_name),
+    }
+}
+
+/// Sheet referenced by a `Custom` field, looking through arrays
+fn reference_target(field_type: &FieldType) -> Option<&str> {
+    match field_type {
+        FieldType::Custom(type_name) => Some(type_name),
+        FieldType::Array(element_type, _) => reference_target(element_type),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn schema(name: &str, fields: Vec<(&str, FieldType)>) -> (String, Schema) {
+        let schema = Schema {
+            name: name.to_string(),
+            fields: fields
+                .into_iter()
+                .enumerate()
+                .map(|(column, (name, field_type))| Field {
+                    name: name.to_string(),
+                    field_type,
+                    columns: vec![column],
+                })
+                .collect(),
+        };
+        (name.to_string(), schema)
+    }
+
+    fn create_test_schemas() -> SchemaMap {
+        SchemaMap::from([
+            schema(
+                "Item",
+                vec![
+                    ("id", FieldType::Int32),
+                    ("name", FieldType::String),
+                    ("level", FieldType::Byte),
+                    ("isUnique", FieldType::Bit(1)),
+                    ("category", FieldType::Custom("ItemCategory".to_string())),
+                    (
+                        "baseParam",
+                        FieldType::Array(
+                            Box::new(FieldType::Struct(vec![
+                                (
+                                    "param".to_string(),
+                                    FieldType::Custom("BaseParam".to_string()),
+                                ),
+                                ("value".to_string(), FieldType::Int16),
+                            ])),
+                            2,
+                        ),
+                    ),
+                    (
+                        "classJob",
+                        FieldType::Array(Box::new(FieldType::Custom("ClassJob".to_string())), 2),
+                    ),
+                ],
+            ),
+            schema(
+                "ItemCategory",
+                vec![("id", FieldType::Uint32), ("name", FieldType::String)],
+            ),
+            schema(
+                "ClassJob",
+                vec![("id", FieldType::Int32), ("name", FieldType::String)],
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_generate_object_types() {
+        let graphql = GraphqlGenerator::new().generate_graphql_schema(&create_test_schemas());
+
+        assert!(graphql.contains("\"\"\"Unsigned 32-bit integer\"\"\"\nscalar UInt32\n"));
+        assert!(graphql.contains(
+            r#""""Row of the Item sheet"""
+type Item {
+  id: Int!
+  name: String!
+  level: Int!
+  isUnique: Boolean!
+  category: ItemCategory
+  categoryId: UInt32!
+  baseParam: [ItemBaseParam!]!
+  classJob: [ClassJob]!
+  classJobIds: [UInt32!]!
+}
+
+"""Record in Item.baseParam"""
+type ItemBaseParam {
+  param: UInt32!
+  value: Int!
+}
+"#
+        ));
+    }
+
+    #[test]
+    fn test_generate_query() {
+        let graphql = GraphqlGenerator::new().generate_graphql_schema(&create_test_schemas());
+
+        assert!(graphql.contains(
+            r#"type Query {
+  """Look up a row of the ClassJob sheet by its key"""
+  classJobByKey(key: Int!): ClassJob
+  """All rows of the ClassJob sheet"""
+  classJobList: [ClassJob!]!
+"#
+        ));
+        assert!(graphql.contains("  itemCategoryByKey(key: UInt32!): ItemCategory\n"));
+        assert!(graphql.contains("  itemList: [Item!]!\n"));
+    }
+}
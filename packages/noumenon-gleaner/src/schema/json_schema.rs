@@ -0,0 +1,307 @@
+use serde_json::{Map, Value as JsonValue, json};
+
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Int64Representation, Schema, SchemaMap};
+
+const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub struct JsonSchemaGenerator {
+    int64_representation: Int64Representation,
+}
+
+impl JsonSchemaGenerator {
+    pub fn new() -> Self {
+        Self {
+            int64_representation: Int64Representation::default(),
+        }
+    }
+
+    /// Set how 64-bit integer values are described, matching the extracted data
+    pub fn with_int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
+
+    /// Generate a JSON Schema document describing the extracted rows of every sheet.
+    ///
+    /// Each sheet gets a `$defs` entry for its row object and an `{Sheet}Id`
+    /// entry for its row key, which `Custom` fields reference with `$ref`.
+    pub fn generate_json_schema(&self, schemas: &SchemaMap) -> JsonValue {
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut defs = Map::new();
+        for schema in &sorted_schemas {
+            defs.insert(
+                id_def_name(&schema.name),
+                json!({
+                    "description": format!("Row key of the {} sheet", schema.name),
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": u32::MAX,
+                }),
+            );
+        }
+        for schema in &sorted_schemas {
+            defs.insert(schema.name.clone(), self.generate_row_schema(schema));
+        }
+
+        json!({
+            "$schema": DRAFT_2020_12,
+            "$comment": "Generated JSON Schema from CSV schemas. Do not edit manually.",
+            "$defs": defs,
+        })
+    }
+
+    fn generate_row_schema(&self, schema: &Schema) -> JsonValue {
+        let members: Vec<(&str, &FieldType)> = schema
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), &field.field_type))
+            .collect();
+
+        let mut row = self.object_schema(&members);
+        row["title"] = json!(schema.name);
+        row
+    }
+
+    fn object_schema(&self, members: &[(&str, &FieldType)]) -> JsonValue {
+        let properties: Map<String, JsonValue> = members
+            .iter()
+            .map(|(name, field_type)| (name.to_string(), self.field_type_to_schema(field_type)))
+            .collect();
+        let required: Vec<&str> = members.iter().map(|(name, _)| *name).collect();
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Convert FieldType to the JSON Schema of its extracted value
+    fn field_type_to_schema(&self, field_type: &FieldType) -> JsonValue {
+        match field_type {
+            FieldType::String => json!({ "type": "string" }),
+            FieldType::Int32 => integer_schema(i32::MIN.into(), i32::MAX.into()),
+            FieldType::Uint32 => integer_schema(0, u32::MAX.into()),
+            FieldType::Int16 => integer_schema(i16::MIN.into(), i16::MAX.into()),
+            FieldType::Uint16 => integer_schema(0, u16::MAX.into()),
+            FieldType::Byte => integer_schema(0, u8::MAX.into()),
+            FieldType::SByte => integer_schema(i8::MIN.into(), i8::MAX.into()),
+            FieldType::Int64 => self.int64_schema("int64", json!(i64::MIN), json!(i64::MAX)),
+            FieldType::Uint64 => self.int64_schema("uint64", json!(0), json!(u64::MAX)),
+            FieldType::Float => json!({ "type": "number" }),
+            FieldType::Bool | FieldType::Bit(_) => json!({ "type": "boolean" }),
+            // Special types keep their JSON type and are annotated with a format
+            FieldType::Image => json!({
+                "type": "string",
+                "format": "image-path",
+                "pattern": "^[0-9]+$",
+            }),
+            FieldType::Row => json!({
+                "type": "integer",
+                "format": "row-id",
+                "minimum": 0,
+                "maximum": u32::MAX,
+            }),
+            FieldType::Key => json!({ "type": "string", "format": "key-string" }),
+            FieldType::Color => json!({
+                "type": "integer",
+                "format": "color-code",
+                "minimum": 0,
+                "maximum": u32::MAX,
+            }),
+            FieldType::Custom(type_name) => {
+                json!({ "$ref": format!("#/$defs/{}", id_def_name(type_name)) })
+            }
+            FieldType::Array(element_type, length) => json!({
+                "type": "array",
+                "items": self.field_type_to_schema(element_type),
+                "minItems": length,
+                "maxItems": length,
+            }),
+            FieldType::Struct(members) => {
+                let members: Vec<(&str, &FieldType)> = members
+                    .iter()
+                    .map(|(name, member_type)| (name.as_str(), member_type))
+                    .collect();
+                self.object_schema(&members)
+            }
+        }
+    }
+
+    fn int64_schema(&self, format: &str, minimum: JsonValue, maximum: JsonValue) -> JsonValue {
+        let pattern = if format == "uint64" {
+            "^[0-9]+$"
+        } else {
+            "^-?[0-9]+$"
+        };
+
+        match self.int64_representation {
+            Int64Representation::BigInt | Int64Representation::String => json!({
+                "type": "string",
+                "format": format,
+                "pattern": pattern,
+            }),
+            Int64Representation::Number => json!({
+                "type": "integer",
+                "format": format,
+                "minimum": minimum,
+                "maximum": maximum,
+            }),
+            Int64Representation::Quad => json!({
+                "type": "array",
+                "format": format,
+                "items": integer_schema(0, u16::MAX.into()),
+                "minItems": 4,
+                "maxItems": 4,
+            }),
+        }
+    }
+}
+
+impl SchemaGenerator for JsonSchemaGenerator {
+    fn name(&self) -> &'static str {
+        "json-schema"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "schema.json"
+    }
+
+    fn supports_inlined_data(&self) -> bool {
+        false
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        format!("{:#}\n", self.generate_json_schema(schemas))
+    }
+}
+
+impl Default for JsonSchemaGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn integer_schema(minimum: i64, maximum: i64) -> JsonValue {
+    json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+}
+
+fn id_def_name(schema_name: &str) -> String {
+    format!("{}Id", schema_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn create_test_schemas() -> SchemaMap {
+        let fields = vec![
+            ("id", FieldType::Int32),
+            ("name", FieldType::String),
+            ("level", FieldType::Byte),
+            ("offset", FieldType::SByte),
+            ("isUnique", FieldType::Bit(1)),
+            ("icon", FieldType::Image),
+            ("category", FieldType::Custom("ItemCategory".to_string())),
+            (
+                "baseParam",
+                FieldType::Array(
+                    Box::new(FieldType::Struct(vec![
+                        (
+                            "param".to_string(),
+                            FieldType::Custom("BaseParam".to_string()),
+                        ),
+                        ("value".to_string(), FieldType::Int16),
+                    ])),
+                    2,
+                ),
+            ),
+        ];
+
+        SchemaMap::from([(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(column, (name, field_type))| Field {
+                        name: name.to_string(),
+                        field_type,
+                        columns: vec![column],
+                    })
+                    .collect(),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_document_structure() {
+        let document = JsonSchemaGenerator::new().generate_json_schema(&create_test_schemas());
+
+        assert_eq!(document["$schema"], json!(DRAFT_2020_12));
+        let defs: Vec<&String> = document["$defs"].as_object().unwrap().keys().collect();
+        assert_eq!(defs, vec!["ItemId", "Item"]);
+
+        let item = &document["$defs"]["Item"];
+        assert_eq!(item["title"], json!("Item"));
+        assert_eq!(item["additionalProperties"], json!(false));
+        assert_eq!(item["required"][0], json!("id"));
+    }
+
+    #[test]
+    fn test_field_schemas() {
+        let document = JsonSchemaGenerator::new().generate_json_schema(&create_test_schemas());
+        let properties = &document["$defs"]["Item"]["properties"];
+
+        assert_eq!(
+            properties["level"],
+            json!({ "type": "integer", "minimum": 0, "maximum": 255 })
+        );
+        assert_eq!(
+            properties["offset"],
+            json!({ "type": "integer", "minimum": -128, "maximum": 127 })
+        );
+        assert_eq!(properties["isUnique"], json!({ "type": "boolean" }));
+        assert_eq!(properties["icon"]["format"], json!("image-path"));
+        assert_eq!(
+            properties["category"],
+            json!({ "$ref": "#/$defs/ItemCategoryId" })
+        );
+        assert_eq!(
+            properties["baseParam"]["items"]["properties"]["param"],
+            json!({ "$ref": "#/$defs/BaseParamId" })
+        );
+        assert_eq!(properties["baseParam"]["minItems"], json!(2));
+        assert_eq!(properties["baseParam"]["maxItems"], json!(2));
+    }
+
+    #[test]
+    fn test_int64_follows_representation() {
+        let generator = JsonSchemaGenerator::new();
+        assert_eq!(
+            generator.field_type_to_schema(&FieldType::Uint64),
+            json!({ "type": "string", "format": "uint64", "pattern": "^[0-9]+$" })
+        );
+
+        let generator =
+            JsonSchemaGenerator::new().with_int64_representation(Int64Representation::Number);
+        assert_eq!(
+            generator.field_type_to_schema(&FieldType::Int64)["minimum"],
+            json!(i64::MIN)
+        );
+
+        let generator =
+            JsonSchemaGenerator::new().with_int64_representation(Int64Representation::Quad);
+        assert_eq!(
+            generator.field_type_to_schema(&FieldType::Int64)["items"]["maximum"],
+            json!(65535)
+        );
+    }
+}
@@ -1,11 +1,44 @@
 mod builder;
+mod data;
+mod effect;
 mod error;
+mod generator;
+mod graphql;
+mod json_schema;
+mod proto;
+mod rust;
+mod sql;
+#[cfg(feature = "sqlite")]
+mod sqlite;
 mod types;
 mod typescript;
 mod utils;
+mod validate;
+mod value;
+mod zod;
 
 // Re-export commonly used types and functions
 pub use builder::SchemaBuilder;
+pub use data::DataExporter;
+pub use effect::EffectSchemaGenerator;
 pub use error::SchemaError;
+pub use generator::{GeneratorRegistry, SchemaGenerator};
+pub use graphql::GraphqlGenerator;
+pub use json_schema::JsonSchemaGenerator;
+pub use proto::ProtoGenerator;
+pub use rust::RustGenerator;
+pub use sql::{SqlDdlGenerator, SqlDialect};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteExporter;
+pub use types::{
+    Diagnostic, Field, FieldType, Int64Representation, RowMap, Schema, SchemaMap, Severity,
+    TypeCoercion,
+};
 pub use typescript::TypeScriptGenerator;
 pub use utils::analyze_missing_files;
+pub use validate::{
+    DanglingReference, DataValidator, IssueKind, ReferenceEdge, ReferenceReport,
+    ReferenceValidator, ValidationIssue, ValidationReport,
+};
+pub use value::Value;
+pub use zod::ZodGenerator;
@@ -0,0 +1,335 @@
+use std::collections::{HashMap, HashSet};
+
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Schema, SchemaMap};
+use super::utils::{record_members, to_pascal_case, to_snake_case, unique_type_name};
+
+/// Protocol Buffers (proto3) message generator.
+///
+/// Top-level field tags come from the CSV column position (`key` → 1, column `0` → 2, ...),
+/// so tags stay stable as long as the sheet's columns do.
+pub struct ProtoGenerator;
+
+/// Message field with its tag, before rendering
+struct ProtoField<'a> {
+    name: &'a str,
+    field_type: &'a FieldType,
+    tag: usize,
+}
+
+impl ProtoGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate proto3 message definitions for all schemas
+    pub fn generate_proto(&self, schemas: &SchemaMap) -> String {
+        let mut content = String::new();
+
+        // Add header comment
+        content.push_str("// Generated Protocol Buffers definitions from CSV schemas\n");
+        content.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+        content.push_str("syntax = \"proto3\";\n");
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Nested messages may not shadow a top-level message inside their parent
+        let sheet_names: HashSet<String> = schemas.keys().cloned().collect();
+
+        for schema in sorted_schemas {
+            let fields: Vec<ProtoField> = schema
+                .fields
+                .iter()
+                .map(|field| ProtoField {
+                    name: &field.name,
+                    field_type: &field.field_type,
+                    // Column 0 is the row key, and tag 0 is not allowed
+                    tag: field.columns.first().map_or(0, |column| column + 1),
+                })
+                .collect();
+
+            content.push('\n');
+            content.push_str(&self.generate_message(
+                &schema.name,
+                &format!("Row of the {} sheet", schema.name),
+                &fields,
+                0,
+                &sheet_names,
+            ));
+        }
+
+        content
+    }
+
+    /// Render a message; `scope` holds the message names visible from its parent
+    fn generate_message(
+        &self,
+        name: &str,
+        doc: &str,
+        fields: &[ProtoField],
+        depth: usize,
+        scope: &HashSet<String>,
+    ) -> String {
+        let indent = "  ".repeat(depth);
+        let mut message = String::new();
+
+        message.push_str(&format!("{}// {}\n", indent, doc));
+        message.push_str(&format!("{}message {} {{\n", indent, name));
+
+        let mut taken = scope.clone();
+        taken.insert(name.to_string());
+
+        // Records are declared as nested messages named after their field
+        let mut nested_names: HashMap<&str, String> = HashMap::new();
+        for field in fields {
+            if let Some(members) = record_members(field.field_type) {
+                let nested_name = unique_type_name(to_pascal_case(field.name), &mut taken);
+                let members: Vec<ProtoField> = members
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (member_name, member_type))| ProtoField {
+                        name: member_name,
+                        field_type: member_type,
+                        tag: index + 1,
+                    })
+                    .collect();
+
+                message.push_str(&self.generate_message(
+                    &nested_name,
+                    &format!("Record in {}.{}", name, field.name),
+                    &members,
+                    depth + 1,
+                    &taken,
+                ));
+                message.push('\n');
+                nested_names.insert(field.name, nested_name);
+            }
+        }
+
+        for field in fields {
+            let nested_name = nested_names.get(field.name).map_or("", String::as_str);
+            message.push_str(&format!(
+                "{}  {}\n",
+                indent,
+                self.generate_field(field, nested_name)
+            ));
+        }
+        message.push_str(&format!("{}}}\n", indent));
+
+        message
+    }
+
+    /// Render a field; `nested_name` names the message of a record field
+    fn generate_field(&self, field: &ProtoField, nested_name: &str) -> String {
+        let (label, element_type) = match field.field_type {
+            FieldType::Array(element_type, _) => ("repeated ", element_type.as_ref()),
+            field_type => ("", field_type),
+        };
+
+        let identifier = to_snake_case(field.name);
+        let mut definition = format!(
+            "{}{} {} = {}",
+            label,
+            field_type_to_proto(element_type, nested_name),
+            identifier,
+            field.tag
+        );
+
+        // Keep the JSON mapping on the original field name used by the extracted data
+        if json_name(&identifier) != field.name {
+            definition.push_str(&format!(" [json_name = \"{}\"]", field.name));
+        }
+        definition.push(';');
+
+        if let Some(comment) = field_comment(element_type) {
+            definition.push_str(&format!(" // {}", comment));
+        }
+
+        definition
+    }
+}
+
+impl SchemaGenerator for ProtoGenerator {
+    fn name(&self) -> &'static str {
+        "proto"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["protobuf"]
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "proto"
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_proto(schemas)
+    }
+}
+
+impl Default for ProtoGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convert FieldType to a proto3 scalar type; records use their nested message `nested_name`
+fn field_type_to_proto(field_type: &FieldType, nested_name: &str) -> String {
+    match field_type {
+        FieldType::String | FieldType::Key | FieldType::Image => "string".to_string(),
+        FieldType::Int32 | FieldType::Int16 | FieldType::SByte => "int32".to_string(),
+        FieldType::Uint32 | FieldType::Uint16 | FieldType::Byte => "uint32".to_string(),
+        FieldType::Int64 => "int64".to_string(),
+        FieldType::Uint64 => "uint64".to_string(),
+        FieldType::Float => "float".to_string(),
+        FieldType::Bool | FieldType::Bit(_) => "bool".to_string(),
+        // Row keys and packed colors are 32-bit unsigned values
+        FieldType::Row | FieldType::Color | FieldType::Custom(_) => "uint32".to_string(),
+        FieldType::Struct(_) => nested_name.to_string(),
+        // Arrays are flattened into `repeated` by the caller
+        FieldType::Array(element_type, _) => field_type_to_proto(element_type, nested_name),
+    }
+}
+
+/// Comment recording what a widened or referencing scalar holds
+fn field_comment(field_type: &FieldType) -> Option<String> {
+    match field_type {
+        FieldType::Custom(type_name) => Some(format!("{} row key", type_name)),
+        FieldType::Int16 => Some("int16".to_string()),
+        FieldType::Uint16 => Some("uint16".to_string()),
+        FieldType::Byte => Some("byte".to_string()),
+        FieldType::SByte => Some("sbyte".to_string()),
+        FieldType::Image => Some("Image".to_string()),
+        FieldType::Row => Some("Row".to_string()),
+        FieldType::Key => Some("Key".to_string()),
+        FieldType::Color => Some("Color".to_string()),
+        _ => None,
+    }
+}
+
+/// JSON name protoc derives from a snake_case field name
+fn json_name(identifier: &str) -> String {
+    let mut name = String::new();
+    let mut capitalize_next = false;
+
+    for ch in identifier.chars() {
+        if ch == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            name.push(ch.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            name.push(ch);
+        }
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn create_test_schemas() -> SchemaMap {
+        let field = |name: &str, field_type, columns: Vec<usize>| Field {
+            name: name.to_string(),
+            field_type,
+            columns,
+        };
+        let base_param = FieldType::Struct(vec![
+            (
+                "param".to_string(),
+                FieldType::Custom("BaseParam".to_string()),
+            ),
+            ("value".to_string(), FieldType::Int16),
+        ]);
+
+        SchemaMap::from([(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: vec![
+                    field("id", FieldType::Int32, vec![0]),
+                    field("name", FieldType::String, vec![1]),
+                    field("level", FieldType::Byte, vec![2]),
+                    field(
+                        "itemUICategory",
+                        FieldType::Custom("ItemUICategory".to_string()),
+                        vec![3],
+                    ),
+                    // Columns 4 and 5 are unused by this sheet
+                    field(
+                        "baseParam",
+                        FieldType::Array(Box::new(base_param), 2),
+                        vec![6, 7, 8, 9],
+                    ),
+                    field("isUnique", FieldType::Bit(1), vec![10]),
+                    field("model", FieldType::Uint64, vec![11]),
+                ],
+            },
+        )])
+    }
+
+    #[test]
+    fn test_generate_message() {
+        let proto = ProtoGenerator::new().generate_proto(&create_test_schemas());
+
+        assert!(proto.contains("syntax = \"proto3\";\n"));
+        assert!(proto.contains(
+            r#"// Row of the Item sheet
+message Item {
+  // Record in Item.baseParam
+  message BaseParam {
+    uint32 param = 1; // BaseParam row key
+    int32 value = 2; // int16
+  }
+
+  int32 id = 1;
+  string name = 2;
+  uint32 level = 3; // byte
+  uint32 item_ui_category = 4 [json_name = "itemUICategory"]; // ItemUICategory row key
+  repeated BaseParam base_param = 7;
+  bool is_unique = 11;
+  uint64 model = 12;
+}
+"#
+        ));
+    }
+
+    #[test]
+    fn test_json_name() {
+        assert_eq!(json_name("base_param"), "baseParam");
+        assert_eq!(json_name("item_ui_category"), "itemUiCategory");
+        assert_eq!(json_name("model2_main"), "model2Main");
+    }
+
+    #[test]
+    fn test_nested_messages_do_not_shadow_sheets() {
+        let mut schemas = create_test_schemas();
+        let record = FieldType::Struct(vec![("value".to_string(), FieldType::Int16)]);
+        let item = schemas.get_mut("Item").unwrap();
+        item.fields.push(Field {
+            name: "item".to_string(),
+            field_type: record,
+            columns: vec![12],
+        });
+        schemas.insert(
+            "BaseParam".to_string(),
+            Schema {
+                name: "BaseParam".to_string(),
+                fields: vec![],
+            },
+        );
+
+        let proto = ProtoGenerator::new().generate_proto(&schemas);
+
+        // BaseParam is a top-level message, Item the parent itself
+        assert!(proto.contains("  message BaseParamRecord {\n"));
+        assert!(proto.contains("  repeated BaseParamRecord base_param = 7;\n"));
+        assert!(proto.contains("  message ItemRecord {\n"));
+        assert!(proto.contains("  ItemRecord item = 13;\n"));
+    }
+}
@@ -0,0 +1,462 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Int64Representation, Schema, SchemaMap};
+use super::utils::{record_members, record_type_name, to_snake_case};
+
+/// Longest fixed-size array serde can (de)serialize; longer arrays become `Vec`
+const MAX_SERDE_ARRAY_LENGTH: usize = 32;
+
+/// Type names emitted ahead of the sheet structs
+const SPECIAL_TYPE_NAMES: &[&str] = &["ImagePath", "RowId", "KeyString", "ColorCode", "Decimal"];
+
+/// Identifiers that cannot be used as field names without escaping
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+pub struct RustGenerator {
+    int64_representation: Int64Representation,
+}
+
+/// Struct definition collected while generating, emitted after its parent
+struct RustStruct<'a> {
+    name: String,
+    doc: String,
+    members: Vec<(&'a str, &'a FieldType)>,
+}
+
+impl RustGenerator {
+    pub fn new() -> Self {
+        Self {
+            int64_representation: Int64Representation::default(),
+        }
+    }
+
+    /// Set how 64-bit integer values are deserialized, matching the extracted data
+    pub fn with_int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
+
+    /// Generate a Rust module with serde structs for all schemas
+    pub fn generate_rust_module(&self, schemas: &SchemaMap) -> String {
+        let mut content = String::new();
+
+        // Add header comment
+        content.push_str("// Generated Rust structs from CSV schemas\n");
+        content.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+        content.push_str("use serde::{Deserialize, Serialize};\n\n");
+
+        // Add special type definitions
+        content.push_str(&self.generate_special_types());
+
+        if self.uses_decimal(schemas) {
+            content.push('\n');
+            content.push_str(DECIMAL_DEFINITION);
+        }
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Newtype IDs for every sheet, including referenced sheets that were not loaded
+        let mut id_targets: BTreeSet<&str> = BTreeSet::new();
+        for schema in &sorted_schemas {
+            id_targets.insert(&schema.name);
+            for field in &schema.fields {
+                for (_, leaf_type, _) in field.leaves() {
+                    if let FieldType::Custom(type_name) = leaf_type {
+                        id_targets.insert(type_name);
+                    }
+                }
+            }
+        }
+        // Record struct names must not collide with any other emitted type
+        let mut taken: HashSet<String> = SPECIAL_TYPE_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .chain(schemas.keys().cloned())
+            .chain(id_targets.iter().map(|target| id_type_name(target)))
+            .collect();
+
+        for target in id_targets {
+            content.push('\n');
+            content.push_str(&generate_id_type(target));
+        }
+
+        for schema in sorted_schemas {
+            let mut pending = vec![RustStruct {
+                name: schema.name.clone(),
+                doc: format!("Row of the {} sheet", schema.name),
+                members: schema
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.as_str(), &field.field_type))
+                    .collect(),
+            }];
+
+            // Nested records are emitted right after the struct that introduced them
+            let mut index = 0;
+            while index < pending.len() {
+                let mut nested = Vec::new();
+                let definition = self.generate_struct(&mut taken, &pending[index], &mut nested);
+                pending.extend(nested);
+                content.push('\n');
+                content.push_str(&definition);
+                index += 1;
+            }
+        }
+
+        content
+    }
+
+    fn generate_special_types(&self) -> String {
+        let mut types = String::new();
+
+        types.push_str("// Special type definitions for game data\n");
+        types.push_str("/// Image file path identifier\n");
+        types.push_str("pub type ImagePath = String;\n\n");
+
+        types.push_str("/// Row reference identifier\n");
+        types.push_str("pub type RowId = u32;\n\n");
+
+        types.push_str("/// Key identifier string\n");
+        types.push_str("pub type KeyString = String;\n\n");
+
+        types.push_str("/// Color code identifier\n");
+        types.push_str("pub type ColorCode = u32;\n");
+
+        types
+    }
+
+    fn generate_struct<'a>(
+        &self,
+        taken: &mut HashSet<String>,
+        definition: &RustStruct<'a>,
+        nested: &mut Vec<RustStruct<'a>>,
+    ) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!("/// {}\n", definition.doc));
+        output.push_str("#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]\n");
+
+        if definition.members.is_empty() {
+            output.push_str(&format!("pub struct {} {{}}\n", definition.name));
+            return output;
+        }
+
+        output.push_str(&format!("pub struct {} {{\n", definition.name));
+        for (name, field_type) in &definition.members {
+            let mut nested_name = String::new();
+            if let Some(members) = record_members(field_type) {
+                nested_name = record_type_name(&definition.name, name, taken);
+                nested.push(RustStruct {
+                    name: nested_name.clone(),
+                    doc: format!("Record in {}.{}", definition.name, name),
+                    members: members
+                        .iter()
+                        .map(|(member_name, member_type)| (member_name.as_str(), member_type))
+                        .collect(),
+                });
+            }
+            let rust_type = self.field_type_to_rust(field_type, &nested_name);
+
+            output.push_str(&format!("    #[serde(rename = \"{}\")]\n", name));
+            output.push_str(&format!(
+                "    pub {}: {},\n",
+                field_identifier(name),
+                rust_type
+            ));
+        }
+        output.push_str("}\n");
+
+        output
+    }
+
+    /// Convert FieldType to a Rust type; `nested_name` names a record struct
+    fn field_type_to_rust(&self, field_type: &FieldType, nested_name: &str) -> String {
+        match field_type {
+            FieldType::String => "String".to_string(),
+            FieldType::Int32 => "i32".to_string(),
+            FieldType::Uint32 => "u32".to_string(),
+            FieldType::Int64 => self.int64_to_rust("i64"),
+            FieldType::Uint64 => self.int64_to_rust("u64"),
+            FieldType::Int16 => "i16".to_string(),
+            FieldType::Uint16 => "u16".to_string(),
+            FieldType::Byte => "u8".to_string(),
+            FieldType::SByte => "i8".to_string(),
+            FieldType::Float => "f32".to_string(),
+            FieldType::Bool | FieldType::Bit(_) => "bool".to_string(),
+            FieldType::Image => "ImagePath".to_string(),
+            FieldType::Row => "RowId".to_string(),
+            FieldType::Key => "KeyString".to_string(),
+            FieldType::Color => "ColorCode".to_string(),
+            FieldType::Custom(type_name) => id_type_name(type_name),
+            FieldType::Array(element_type, length) => {
+                let element = self.field_type_to_rust(element_type, nested_name);
+                if *length <= MAX_SERDE_ARRAY_LENGTH {
+                    format!("[{}; {}]", element, length)
+                } else {
+                    format!("Vec<{}>", element)
+                }
+            }
+            FieldType::Struct(_) => nested_name.to_string(),
+        }
+    }
+
+    fn int64_to_rust(&self, integer: &str) -> String {
+        match self.int64_representation {
+            Int64Representation::BigInt | Int64Representation::String => {
+                format!("Decimal<{}>", integer)
+            }
+            Int64Representation::Number => integer.to_string(),
+            Int64Representation::Quad => "[u16; 4]".to_string(),
+        }
+    }
+
+    /// Whether any field holds 64-bit integers written as decimal strings
+    fn uses_decimal(&self, schemas: &SchemaMap) -> bool {
+        let decimal = matches!(
+            self.int64_representation,
+            Int64Representation::BigInt | Int64Representation::String
+        );
+
+        decimal
+            && schemas.values().any(|schema| {
+                schema.fields.iter().any(|field| {
+                    field.leaves().iter().any(|(_, leaf_type, _)| {
+                        matches!(leaf_type, FieldType::Int64 | FieldType::Uint64)
+                    })
+                })
+            })
+    }
+}
+
+impl SchemaGenerator for RustGenerator {
+    fn name(&self) -> &'static str {
+        "rust"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["rs"]
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "rs"
+    }
+
+    fn supports_inlined_data(&self) -> bool {
+        false
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_rust_module(schemas)
+    }
+}
+
+impl Default for RustGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wrapper for 64-bit integers that the extracted data stores as decimal strings
+const DECIMAL_DEFINITION: &str = r#"/// 64-bit integer stored as a decimal string
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Decimal<T>(pub T);
+
+impl<T: std::fmt::Display> Serialize for Decimal<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Decimal<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map(Decimal).map_err(serde::de::Error::custom)
+    }
+}
+"#;
+
+fn generate_id_type(schema_name: &str) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!("/// Row key of the {} sheet\n", schema_name));
+    output.push_str(
+        "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]\n",
+    );
+    output.push_str("#[serde(transparent)]\n");
+    output.push_str(&format!(
+        "pub struct {}(pub u32);\n",
+        id_type_name(schema_name)
+    ));
+
+    output
+}
+
+fn id_type_name(schema_name: &str) -> String {
+    format!("{}Id", schema_name)
+}
+
+/// Convert a camelCase field name to a snake_case Rust identifier
+fn field_identifier(name: &str) -> String {
+    let mut identifier = to_snake_case(name);
+    if identifier == "_" || RUST_KEYWORDS.contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn create_test_schemas() -> SchemaMap {
+        let fields = vec![
+            ("id", FieldType::Int32),
+            ("name", FieldType::String),
+            ("level", FieldType::Byte),
+            ("offset", FieldType::SByte),
+            ("isUnique", FieldType::Bit(1)),
+            (
+                "itemUICategory",
+                FieldType::Custom("ItemUICategory".to_string()),
+            ),
+            ("type", FieldType::Uint16),
+            (
+                "baseParam",
+                FieldType::Array(
+                    Box::new(FieldType::Struct(vec![
+                        (
+                            "param".to_string(),
+                            FieldType::Custom("BaseParam".to_string()),
+                        ),
+                        ("value".to_string(), FieldType::Int16),
+                    ])),
+                    2,
+                ),
+            ),
+            ("model", FieldType::Uint64),
+        ];
+
+        SchemaMap::from([(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(column, (name, field_type))| Field {
+                        name: name.to_string(),
+                        field_type,
+                        columns: vec![column],
+                    })
+                    .collect(),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_generate_structs() {
+        let rust = RustGenerator::new().generate_rust_module(&create_test_schemas());
+
+        assert!(rust.contains("use serde::{Deserialize, Serialize};\n"));
+        assert!(rust.contains("pub struct Decimal<T>(pub T);\n"));
+        assert!(rust.contains("#[serde(transparent)]\npub struct BaseParamId(pub u32);\n"));
+        assert!(rust.contains("pub struct ItemUICategoryId(pub u32);\n"));
+        assert!(rust.contains(
+            r#"/// Row of the Item sheet
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Item {
+    #[serde(rename = "id")]
+    pub id: i32,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "level")]
+    pub level: u8,
+    #[serde(rename = "offset")]
+    pub offset: i8,
+    #[serde(rename = "isUnique")]
+    pub is_unique: bool,
+    #[serde(rename = "itemUICategory")]
+    pub item_ui_category: ItemUICategoryId,
+    #[serde(rename = "type")]
+    pub type_: u16,
+    #[serde(rename = "baseParam")]
+    pub base_param: [ItemBaseParam; 2],
+    #[serde(rename = "model")]
+    pub model: Decimal<u64>,
+}
+
+/// Record in Item.baseParam
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ItemBaseParam {
+    #[serde(rename = "param")]
+    pub param: BaseParamId,
+    #[serde(rename = "value")]
+    pub value: i16,
+}
+"#
+        ));
+    }
+
+    #[test]
+    fn test_record_names_avoid_every_emitted_type() {
+        let mut schemas = create_test_schemas();
+        for name in ["ItemBaseParam", "ItemBaseParamRecord"] {
+            schemas.insert(
+                name.to_string(),
+                Schema {
+                    name: name.to_string(),
+                    fields: vec![],
+                },
+            );
+        }
+
+        let rust = RustGenerator::new().generate_rust_module(&schemas);
+        assert!(rust.contains("    pub base_param: [ItemBaseParamRecord2; 2],\n"));
+        assert!(rust.contains("pub struct ItemBaseParamRecord2 {\n"));
+        assert_eq!(rust.matches("pub struct ItemBaseParamRecord {}").count(), 1);
+    }
+
+    #[test]
+    fn test_int64_follows_representation() {
+        let schemas = create_test_schemas();
+
+        let generator = RustGenerator::new().with_int64_representation(Int64Representation::Number);
+        let rust = generator.generate_rust_module(&schemas);
+        assert!(rust.contains("    pub model: u64,\n"));
+        assert!(!rust.contains("Decimal"));
+
+        let generator = RustGenerator::new().with_int64_representation(Int64Representation::Quad);
+        assert_eq!(generator.int64_to_rust("i64"), "[u16; 4]");
+    }
+
+    #[test]
+    fn test_field_identifier() {
+        assert_eq!(field_identifier("itemUICategory"), "item_ui_category");
+        assert_eq!(field_identifier("type"), "type_");
+        assert_eq!(field_identifier("_"), "__");
+    }
+
+    #[test]
+    fn test_long_arrays_use_vec() {
+        let generator = RustGenerator::new();
+        let long = FieldType::Array(Box::new(FieldType::Byte), 33);
+        let short = FieldType::Array(Box::new(FieldType::Byte), 32);
+
+        assert_eq!(generator.field_type_to_rust(&long, "Unused"), "Vec<u8>");
+        assert_eq!(generator.field_type_to_rust(&short, "Unused"), "[u8; 32]");
+    }
+}
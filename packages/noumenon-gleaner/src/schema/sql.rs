@@ -0,0 +1,413 @@
+use std::collections::HashSet;
+
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Schema, SchemaMap};
+
+/// SQL dialect targeted by generated DDL
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SqlDialect {
+    #[default]
+    Postgres,
+    Sqlite,
+}
+
+pub struct SqlDdlGenerator {
+    dialect: SqlDialect,
+}
+
+impl SqlDdlGenerator {
+    pub fn new() -> Self {
+        Self {
+            dialect: SqlDialect::default(),
+        }
+    }
+
+    /// Set the SQL dialect of the generated statements
+    pub fn with_dialect(mut self, dialect: SqlDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Generate `CREATE TABLE` statements for all schemas.
+    ///
+    /// Tables are ordered so referenced tables come first. References that close a
+    /// cycle are deferred: Postgres adds them with `ALTER TABLE` after all tables,
+    /// SQLite keeps them inline as `DEFERRABLE INITIALLY DEFERRED`. References to
+    /// sheets missing from `schemas` get no constraint.
+    ///
+    /// Reference columns stay nullable: the `0` "no reference" sentinel has to be
+    /// loaded as `NULL` to satisfy the constraints.
+    pub fn generate_ddl(&self, schemas: &SchemaMap) -> String {
+        let mut content = String::new();
+
+        content.push_str("-- Generated SQL DDL from CSV schemas\n");
+        content.push_str("-- This file is auto-generated. Do not edit manually.\n");
+
+        let mut created = HashSet::new();
+        let mut deferred = Vec::new();
+
+        for schema in table_order(schemas) {
+            content.push('\n');
+            content.push_str(&self.generate_table(schema, schemas, &created, &mut deferred));
+            created.insert(schema.name.as_str());
+        }
+
+        if !deferred.is_empty() {
+            content.push('\n');
+            content.push_str(&deferred.concat());
+        }
+
+        content
+    }
+
+    fn generate_table(
+        &self,
+        schema: &Schema,
+        schemas: &SchemaMap,
+        created: &HashSet<&str>,
+        deferred: &mut Vec<String>,
+    ) -> String {
+        let table = quote_identifier(&schema.name);
+        let mut columns = Vec::new();
+        let mut indexes = Vec::new();
+
+        for field in &schema.fields {
+            for (leaf_name, field_type, column) in field.leaves() {
+                let name = sql_column_name(&leaf_name);
+                let mut definition = format!(
+                    "{} {}",
+                    quote_identifier(&name),
+                    self.sql_type(field_type, schemas)
+                );
+
+                if column == 0 {
+                    definition.push_str(" PRIMARY KEY");
+                }
+
+                if let FieldType::Custom(target) = field_type {
+                    // A reference to a table that does not exist yet closes a cycle
+                    let is_cyclic = target != &schema.name && !created.contains(target.as_str());
+                    match (is_cyclic, self.dialect) {
+                        // No table will ever exist for an unknown sheet
+                        _ if !schemas.contains_key(target) => {}
+                        (false, _) => {
+                            definition.push_str(&format!(" REFERENCES {}", quote_identifier(target)))
+                        }
+                        (true, SqlDialect::Sqlite) => definition.push_str(&format!(
+                            " REFERENCES {} DEFERRABLE INITIALLY DEFERRED",
+                            quote_identifier(target)
+                        )),
+                        (true, SqlDialect::Postgres) => deferred.push(format!(
+                            "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} DEFERRABLE INITIALLY DEFERRED;\n",
+                            table,
+                            quote_identifier(&format!("{}_{}_fkey", schema.name, name)),
+                            quote_identifier(&name),
+                            quote_identifier(target)
+                        )),
+                    }
+
+                    indexes.push(format!(
+                        "CREATE INDEX {} ON {} ({});\n",
+                        quote_identifier(&format!("{}_{}", schema.name, name)),
+                        table,
+                        quote_identifier(&name)
+                    ));
+                }
+
+                columns.push(format!("    {}", definition));
+            }
+        }
+
+        format!(
+            "CREATE TABLE {} (\n{}\n);\n{}",
+            table,
+            columns.join(",\n"),
+            indexes.concat()
+        )
+    }
+
+    fn sql_type(&self, field_type: &FieldType, schemas: &SchemaMap) -> &'static str {
+        match field_type {
+            FieldType::String | FieldType::Key | FieldType::Image => "TEXT",
+            FieldType::Int32 | FieldType::Uint16 => "INTEGER",
+            FieldType::Int16 | FieldType::Byte | FieldType::SByte => "SMALLINT",
+            FieldType::Uint32 | FieldType::Int64 | FieldType::Color | FieldType::Row => "BIGINT",
+            FieldType::Uint64 => match self.dialect {
+                SqlDialect::Postgres => "NUMERIC(20, 0)",
+                // SQLite integers are signed 64-bit, so uint64 keeps its raw bits
+                SqlDialect::Sqlite => "INTEGER",
+            },
+            FieldType::Float => "REAL",
+            FieldType::Bool | FieldType::Bit(_) => "BOOLEAN",
+            // References share the type of the target's key column
+            FieldType::Custom(target) => schemas
+                .get(target)
+                .and_then(key_type)
+                .map(|key_type| self.sql_type(key_type, schemas))
+                .unwrap_or("BIGINT"),
+            // Composite types are flattened into their leaves before mapping
+            FieldType::Array(..) | FieldType::Struct(_) => "TEXT",
+        }
+    }
+}
+
+impl SchemaGenerator for SqlDdlGenerator {
+    fn name(&self) -> &'static str {
+        "sql"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["sql-ddl"]
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "sql"
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_ddl(schemas)
+    }
+}
+
+impl Default for SqlDdlGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type of the key column (CSV column 0) unless it is itself a reference
+fn key_type(schema: &Schema) -> Option<&FieldType> {
+    schema
+        .fields
+        .iter()
+        .flat_map(|field| field.leaves())
+        .find(|(_, _, column)| *column == 0)
+        .map(|(_, field_type, _)| field_type)
+        .filter(|field_type| !matches!(field_type, FieldType::Custom(_)))
+}
+
+/// Order schemas so that referenced tables are created before their referrers.
+///
+/// Schemas are visited by name for stable output; edges back into a schema that is
+/// still being visited (cycles) are left for the caller to defer.
+fn table_order(schemas: &SchemaMap) -> Vec<&Schema> {
+    let mut names: Vec<&String> = schemas.keys().collect();
+    names.sort();
+
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for name in names {
+        visit_schema(name, schemas, &mut visited, &mut order);
+    }
+    order
+}
+
+fn visit_schema<'a>(
+    name: &str,
+    schemas: &'a SchemaMap,
+    visited: &mut HashSet<&'a str>,
+    order: &mut Vec<&'a Schema>,
+) {
+    let Some(schema) = schemas.get(name) else {
+        return;
+    };
+    if !visited.insert(schema.name.as_str()) {
+        return;
+    }
+
+    let mut targets: Vec<&str> = schema
+        .fields
+        .iter()
+        .flat_map(|field| field.leaves())
+        .filter_map(|(_, field_type, _)| match field_type {
+            FieldType::Custom(target) => Some(target.as_str()),
+            _ => None,
+        })
+        .collect();
+    targets.sort();
+    targets.dedup();
+
+    for target in targets {
+        visit_schema(target, schemas, visited, order);
+    }
+    order.push(schema);
+}
+
+/// Flatten a leaf name like `baseParam[0].param` into `baseParam_0_param`
+pub(super) fn sql_column_name(leaf_name: &str) -> String {
+    leaf_name.replace(['[', '.'], "_").replace(']', "")
+}
+
+pub(super) fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn field(name: &str, field_type: FieldType, columns: Vec<usize>) -> Field {
+        Field {
+            name: name.to_string(),
+            field_type,
+            columns,
+        }
+    }
+
+    fn schema(name: &str, fields: Vec<Field>) -> (String, Schema) {
+        (
+            name.to_string(),
+            Schema {
+                name: name.to_string(),
+                fields,
+            },
+        )
+    }
+
+    fn custom(name: &str) -> FieldType {
+        FieldType::Custom(name.to_string())
+    }
+
+    /// ClassJob <-> ClassJobCategory form a cycle, ClassJob also references itself
+    fn create_test_schemas() -> SchemaMap {
+        SchemaMap::from([
+            schema(
+                "ClassJob",
+                vec![
+                    field("id", FieldType::Int32, vec![0]),
+                    field("name", FieldType::String, vec![1]),
+                    field("classJobParent", custom("ClassJob"), vec![2]),
+                    field("classJobCategory", custom("ClassJobCategory"), vec![3]),
+                    field("isLimitedJob", FieldType::Bit(1), vec![4]),
+                ],
+            ),
+            schema(
+                "ClassJobCategory",
+                vec![
+                    field("id", FieldType::Uint32, vec![0]),
+                    field("name", FieldType::String, vec![1]),
+                    field("classJob", custom("ClassJob"), vec![2]),
+                ],
+            ),
+            schema(
+                "BaseParam",
+                vec![
+                    field("id", FieldType::Int32, vec![0]),
+                    field("order", FieldType::Byte, vec![1]),
+                    field("packedIcon", FieldType::Uint64, vec![2]),
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_type_mapping() {
+        let schemas = create_test_schemas();
+        let generator = SqlDdlGenerator::new();
+
+        assert_eq!(generator.sql_type(&FieldType::Byte, &schemas), "SMALLINT");
+        assert_eq!(generator.sql_type(&FieldType::Uint32, &schemas), "BIGINT");
+        assert_eq!(
+            generator.sql_type(&FieldType::Bit(0x80), &schemas),
+            "BOOLEAN"
+        );
+        assert_eq!(
+            generator.sql_type(&FieldType::Uint64, &schemas),
+            "NUMERIC(20, 0)"
+        );
+        assert_eq!(
+            generator
+                .with_dialect(SqlDialect::Sqlite)
+                .sql_type(&FieldType::Uint64, &schemas),
+            "INTEGER"
+        );
+
+        // References take the key type of their target, or BIGINT if unknown
+        let generator = SqlDdlGenerator::new();
+        assert_eq!(
+            generator.sql_type(&custom("ClassJobCategory"), &schemas),
+            "BIGINT"
+        );
+        assert_eq!(generator.sql_type(&custom("ClassJob"), &schemas), "INTEGER");
+        assert_eq!(generator.sql_type(&custom("Missing"), &schemas), "BIGINT");
+    }
+
+    #[test]
+    fn test_postgres_defers_cyclic_references() {
+        let ddl = SqlDdlGenerator::new().generate_ddl(&create_test_schemas());
+
+        assert_eq!(
+            ddl,
+            r#"-- Generated SQL DDL from CSV schemas
+-- This file is auto-generated. Do not edit manually.
+
+CREATE TABLE "BaseParam" (
+    "id" INTEGER PRIMARY KEY,
+    "order" SMALLINT,
+    "packedIcon" NUMERIC(20, 0)
+);
+
+CREATE TABLE "ClassJobCategory" (
+    "id" BIGINT PRIMARY KEY,
+    "name" TEXT,
+    "classJob" INTEGER
+);
+CREATE INDEX "ClassJobCategory_classJob" ON "ClassJobCategory" ("classJob");
+
+CREATE TABLE "ClassJob" (
+    "id" INTEGER PRIMARY KEY,
+    "name" TEXT,
+    "classJobParent" INTEGER REFERENCES "ClassJob",
+    "classJobCategory" BIGINT REFERENCES "ClassJobCategory",
+    "isLimitedJob" BOOLEAN
+);
+CREATE INDEX "ClassJob_classJobParent" ON "ClassJob" ("classJobParent");
+CREATE INDEX "ClassJob_classJobCategory" ON "ClassJob" ("classJobCategory");
+
+ALTER TABLE "ClassJobCategory" ADD CONSTRAINT "ClassJobCategory_classJob_fkey" FOREIGN KEY ("classJob") REFERENCES "ClassJob" DEFERRABLE INITIALLY DEFERRED;
+"#
+        );
+    }
+
+    #[test]
+    fn test_sqlite_keeps_cyclic_references_inline() {
+        let ddl = SqlDdlGenerator::new()
+            .with_dialect(SqlDialect::Sqlite)
+            .generate_ddl(&create_test_schemas());
+
+        assert!(ddl.contains(
+            "    \"classJob\" INTEGER REFERENCES \"ClassJob\" DEFERRABLE INITIALLY DEFERRED\n"
+        ));
+        assert!(ddl.contains("    \"classJobParent\" INTEGER REFERENCES \"ClassJob\",\n"));
+        assert!(!ddl.contains("ALTER TABLE"));
+    }
+
+    #[test]
+    fn test_unknown_reference_target_has_no_constraint() {
+        let schemas = SchemaMap::from([schema(
+            "Item",
+            vec![
+                field("id", FieldType::Int32, vec![0]),
+                field("itemUICategory", custom("ItemUICategory"), vec![1]),
+            ],
+        )]);
+
+        for dialect in [SqlDialect::Postgres, SqlDialect::Sqlite] {
+            let ddl = SqlDdlGenerator::new()
+                .with_dialect(dialect)
+                .generate_ddl(&schemas);
+
+            assert!(ddl.contains("    \"itemUICategory\" BIGINT\n"));
+            assert!(!ddl.contains("REFERENCES"));
+            assert!(!ddl.contains("ALTER TABLE"));
+        }
+    }
+
+    #[test]
+    fn test_flattened_column_names() {
+        assert_eq!(sql_column_name("baseParam[0].param"), "baseParam_0_param");
+        assert_eq!(sql_column_name("price.mid"), "price_mid");
+        assert_eq!(quote_identifier("odd\"name"), "\"odd\"\"name\"");
+    }
+}
@@ -0,0 +1,370 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, params_from_iter, types::Value as SqlValue};
+
+use super::error::SchemaError;
+use super::sql::{SqlDdlGenerator, SqlDialect, quote_identifier};
+use super::types::{FieldType, RowMap, Schema, SchemaMap};
+use super::value::Value;
+use crate::constants::ZERO_SENTINEL;
+
+pub struct SqliteExporter;
+
+impl SqliteExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Write all schemas and their data rows to a fresh SQLite database file.
+    ///
+    /// Tables come from the SQLite dialect of [`SqlDdlGenerator`]. The database is
+    /// built in a sibling temporary file and renamed over `database_path` only after
+    /// the transaction commits, so a failure leaves an existing file untouched.
+    pub fn export_and_save<P: AsRef<Path>>(
+        &self,
+        schemas: &SchemaMap,
+        rows: &RowMap,
+        database_path: P,
+    ) -> Result<(), SchemaError> {
+        let path = database_path.as_ref();
+
+        // Create parent directories if they don't exist
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let temp_path = temp_path_for(path);
+        if temp_path.exists() {
+            fs::remove_file(&temp_path)?;
+        }
+
+        let result = Connection::open(&temp_path)
+            .map_err(SchemaError::from)
+            .and_then(|mut connection| self.export(&mut connection, schemas, rows));
+        if let Err(error) = result {
+            let _ = fs::remove_file(&temp_path);
+            return Err(error);
+        }
+
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    fn export(
+        &self,
+        connection: &mut Connection,
+        schemas: &SchemaMap,
+        rows: &RowMap,
+    ) -> Result<(), SchemaError> {
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Game data may contain dangling keys, so the declared foreign keys are not
+        // enforced while loading (check them with `PRAGMA foreign_key_check`)
+        connection.pragma_update(None, "foreign_keys", false)?;
+        let transaction = connection.transaction()?;
+
+        let ddl = SqlDdlGenerator::new()
+            .with_dialect(SqlDialect::Sqlite)
+            .generate_ddl(schemas);
+        transaction.execute_batch(&ddl)?;
+
+        for schema in &sorted_schemas {
+            let records = rows.get(&schema.name).map(Vec::as_slice).unwrap_or(&[]);
+            let placeholders = vec!["?"; schema.column_count()].join(", ");
+            let mut statement = transaction.prepare(&format!(
+                "INSERT INTO {} VALUES ({})",
+                quote_identifier(&schema.name),
+                placeholders
+            ))?;
+
+            for record in records {
+                statement.execute(params_from_iter(row_values(schema, record)?))?;
+            }
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+impl Default for SqliteExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sibling path the database is built in before it replaces `path`
+fn temp_path_for(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+    path.with_file_name(file_name)
+}
+
+/// Parse a data row into SQL values in column order.
+///
+/// References holding the `0` "no reference" sentinel become `NULL` so they satisfy
+/// the declared foreign keys.
+fn row_values(schema: &Schema, record: &csv::StringRecord) -> Result<Vec<SqlValue>, SchemaError> {
+    let row_key = record.get(0).unwrap_or_default();
+    if record.len() != schema.column_count() {
+        return Err(SchemaError::ColumnCountMismatch {
+            sheet: schema.name.clone(),
+            row_key: row_key.to_string(),
+            expected: schema.column_count(),
+            found: record.len(),
+        });
+    }
+
+    let mut values = Vec::with_capacity(record.len());
+    for field in &schema.fields {
+        for (leaf_name, field_type, column) in field.leaves() {
+            let raw = record.get(column).unwrap_or_default();
+            let value =
+                field_type
+                    .parse_value(raw)
+                    .map_err(|reason| SchemaError::InvalidValue {
+                        sheet: schema.name.clone(),
+                        row_key: row_key.to_string(),
+                        column: leaf_name,
+                        value: raw.to_string(),
+                        reason,
+                    })?;
+            if matches!(field_type, FieldType::Custom(_)) && raw.trim() == ZERO_SENTINEL {
+                values.push(SqlValue::Null);
+            } else {
+                values.push(sql_value(value));
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+fn sql_value(value: Value) -> SqlValue {
+    match value {
+        Value::String(value) => SqlValue::Text(value),
+        Value::Int(value) | Value::Int64(value) => SqlValue::Integer(value),
+        // SQLite integers are signed, so uint64 keeps its raw bits
+        Value::Uint64(value) => SqlValue::Integer(value as i64),
+        Value::Float(value) => SqlValue::Real(value),
+        Value::Bool(value) => SqlValue::Integer(value.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+    use tempfile::TempDir;
+
+    fn create_test_data() -> (SchemaMap, RowMap) {
+        let mut schemas = SchemaMap::new();
+        schemas.insert(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: vec![
+                    Field {
+                        name: "id".to_string(),
+                        field_type: FieldType::Int32,
+                        columns: vec![0],
+                    },
+                    Field {
+                        name: "name".to_string(),
+                        field_type: FieldType::String,
+                        columns: vec![1],
+                    },
+                    Field {
+                        name: "isUnique".to_string(),
+                        field_type: FieldType::Bit(1),
+                        columns: vec![2],
+                    },
+                    Field {
+                        name: "baseParam".to_string(),
+                        field_type: FieldType::Array(
+                            Box::new(FieldType::Struct(vec![
+                                (
+                                    "param".to_string(),
+                                    FieldType::Custom("BaseParam".to_string()),
+                                ),
+                                ("value".to_string(), FieldType::Float),
+                            ])),
+                            2,
+                        ),
+                        columns: vec![3, 4, 5, 6],
+                    },
+                ],
+            },
+        );
+        schemas.insert(
+            "BaseParam".to_string(),
+            Schema {
+                name: "BaseParam".to_string(),
+                fields: vec![
+                    Field {
+                        name: "id".to_string(),
+                        field_type: FieldType::Int32,
+                        columns: vec![0],
+                    },
+                    Field {
+                        name: "name".to_string(),
+                        field_type: FieldType::String,
+                        columns: vec![1],
+                    },
+                ],
+            },
+        );
+
+        let mut rows = RowMap::new();
+        rows.insert(
+            "Item".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["1", "Sword", "True", "12", "5.5", "0", "0"]),
+                csv::StringRecord::from(vec!["2", "Shield", "False", "13", "1", "12", "2"]),
+            ],
+        );
+        rows.insert(
+            "BaseParam".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["12", "Strength"]),
+                csv::StringRecord::from(vec!["13", "Dexterity"]),
+            ],
+        );
+
+        (schemas, rows)
+    }
+
+    #[test]
+    fn test_export_and_save() {
+        let temp_dir = TempDir::new().unwrap();
+        let database_path = temp_dir.path().join("data/game.sqlite");
+        let (schemas, rows) = create_test_data();
+
+        SqliteExporter::new()
+            .export_and_save(&schemas, &rows, &database_path)
+            .unwrap();
+
+        let connection = Connection::open(&database_path).unwrap();
+        let joined: Vec<(String, String, f64)> = connection
+            .prepare(
+                r#"SELECT i.name, b.name, i.baseParam_0_value FROM "Item" i
+                   JOIN "BaseParam" b ON b.id = i.baseParam_0_param ORDER BY i.id"#,
+            )
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            joined,
+            vec![
+                ("Sword".to_string(), "Strength".to_string(), 5.5),
+                ("Shield".to_string(), "Dexterity".to_string(), 1.0),
+            ]
+        );
+
+        let is_unique: bool = connection
+            .query_row(r#"SELECT isUnique FROM "Item" WHERE id = 1"#, [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert!(is_unique);
+
+        let foreign_keys: i64 = connection
+            .query_row(
+                r#"SELECT COUNT(*) FROM pragma_foreign_key_list('Item') WHERE "table" = 'BaseParam'"#,
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(foreign_keys, 2);
+
+        let indexes: Vec<String> = connection
+            .prepare("SELECT name FROM pragma_index_list('Item') ORDER BY name")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            indexes,
+            vec!["Item_baseParam_0_param", "Item_baseParam_1_param"]
+        );
+    }
+
+    #[test]
+    fn test_zero_sentinel_satisfies_foreign_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let database_path = temp_dir.path().join("game.sqlite");
+        let (schemas, rows) = create_test_data();
+
+        SqliteExporter::new()
+            .export_and_save(&schemas, &rows, &database_path)
+            .unwrap();
+
+        let connection = Connection::open(&database_path).unwrap();
+        let (param, value): (Option<i64>, f64) = connection
+            .query_row(
+                r#"SELECT baseParam_1_param, baseParam_1_value FROM "Item" WHERE id = 1"#,
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(param, None);
+        assert_eq!(value, 0.0);
+
+        let violations: i64 = connection
+            .query_row("SELECT COUNT(*) FROM pragma_foreign_key_check", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(violations, 0);
+    }
+
+    #[test]
+    fn test_invalid_row_keeps_existing_database() {
+        let temp_dir = TempDir::new().unwrap();
+        let database_path = temp_dir.path().join("game.sqlite");
+        let (schemas, mut rows) = create_test_data();
+
+        SqliteExporter::new()
+            .export_and_save(&schemas, &rows, &database_path)
+            .unwrap();
+
+        rows.get_mut("Item").unwrap()[1] =
+            csv::StringRecord::from(vec!["2", "Shield", "yes", "13", "1", "12", "2"]);
+
+        match SqliteExporter::new().export_and_save(&schemas, &rows, &database_path) {
+            Err(SchemaError::InvalidValue { column, .. }) => assert_eq!(column, "isUnique"),
+            other => panic!("Expected InvalidValue error, got {:?}", other),
+        }
+
+        // The previous export survives and no temporary file is left behind
+        let connection = Connection::open(&database_path).unwrap();
+        let items: i64 = connection
+            .query_row(r#"SELECT COUNT(*) FROM "Item""#, [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(items, 2);
+        assert!(!temp_path_for(&database_path).exists());
+    }
+
+    #[test]
+    fn test_invalid_row_leaves_no_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let database_path = temp_dir.path().join("game.sqlite");
+        let (schemas, mut rows) = create_test_data();
+        rows.get_mut("Item").unwrap()[1] =
+            csv::StringRecord::from(vec!["2", "Shield", "yes", "13", "1", "12", "2"]);
+
+        assert!(
+            SqliteExporter::new()
+                .export_and_save(&schemas, &rows, &database_path)
+                .is_err()
+        );
+        assert!(!database_path.exists());
+        assert!(!temp_path_for(&database_path).exists());
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FieldType {
@@ -6,6 +7,8 @@ pub enum FieldType {
     String,
     Int32,
     Uint32,
+    Int64,  // int64 - plain integer or packed quad "a, b, c, d" of 16-bit parts
+    Uint64, // uint64 - same encoding as int64
     Int16,
     Uint16,
     Byte,
@@ -22,12 +25,96 @@ pub enum FieldType {
 
     // Custom types that reference other CSV files
     Custom(String),
+
+    // Fixed-length array collapsed from indexed columns (e.g., BaseParam[0..5])
+    Array(Box<FieldType>, usize),
+
+    // Inline record of named members (e.g., zipped { param, value } pairs)
+    Struct(Vec<(String, FieldType)>),
+}
+
+impl FieldType {
+    /// Inclusive value range of integer types that fit in a JavaScript number
+    pub fn integer_bounds(&self) -> Option<(i64, i64)> {
+        match self {
+            FieldType::Int32 => Some((i32::MIN.into(), i32::MAX.into())),
+            FieldType::Int16 => Some((i16::MIN.into(), i16::MAX.into())),
+            FieldType::Uint16 => Some((0, u16::MAX.into())),
+            FieldType::Byte => Some((0, u8::MAX.into())),
+            FieldType::SByte => Some((i8::MIN.into(), i8::MAX.into())),
+            // Colors are packed 32-bit integers, rows and custom types hold row keys
+            FieldType::Uint32 | FieldType::Color | FieldType::Row | FieldType::Custom(_) => {
+                Some((0, u32::MAX.into()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// How 64-bit integer columns are represented in generated types and extracted data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Int64Representation {
+    /// Decimal string that decodes to a `bigint` (JSON has no bigint literal)
+    #[default]
+    BigInt,
+    /// Decimal string
+    String,
+    /// Plain number (values above 2^53 lose precision in JavaScript)
+    Number,
+    /// Tuple of four 16-bit parts, least significant first, as packed in the CSV
+    Quad,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Field {
     pub name: String,
     pub field_type: FieldType,
+    /// CSV column positions backing this field, in the order its type is read
+    pub columns: Vec<usize>,
+}
+
+impl Field {
+    /// Scalar cells of this field as (column name, leaf type, CSV column).
+    ///
+    /// Arrays and records are walked in column order; element and member names
+    /// follow the `name[i].member` form used in error messages.
+    pub fn leaves(&self) -> Vec<(String, &FieldType, usize)> {
+        let mut leaves = Vec::new();
+        collect_leaves(
+            &self.name,
+            &self.field_type,
+            &mut self.columns.iter(),
+            &mut leaves,
+        );
+        leaves
+    }
+}
+
+fn collect_leaves<'a>(
+    column_name: &str,
+    field_type: &'a FieldType,
+    columns: &mut std::slice::Iter<usize>,
+    leaves: &mut Vec<(String, &'a FieldType, usize)>,
+) {
+    match field_type {
+        FieldType::Array(element_type, length) => {
+            for i in 0..*length {
+                let element_name = format!("{}[{}]", column_name, i);
+                collect_leaves(&element_name, element_type, columns, leaves);
+            }
+        }
+        FieldType::Struct(members) => {
+            for (member_name, member_type) in members {
+                let member_column = format!("{}.{}", column_name, member_name);
+                collect_leaves(&member_column, member_type, columns, leaves);
+            }
+        }
+        _ => {
+            if let Some(column) = columns.next() {
+                leaves.push((column_name.to_string(), field_type, *column));
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,8 +123,87 @@ pub struct Schema {
     pub fields: Vec<Field>,
 }
 
+impl Schema {
+    /// Number of CSV columns a data row of this schema must have
+    pub fn column_count(&self) -> usize {
+        self.fields.iter().map(|field| field.columns.len()).sum()
+    }
+}
+
+/// An unknown type token that was coerced to `FieldType::String`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeCoercion {
+    pub path: String,
+    pub column: usize,
+    pub token: String,
+}
+
+/// How much attention a build diagnostic needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+}
+
+/// Something noteworthy found while building schemas that did not stop the build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// A sheet's CSV file was read
+    SheetLoaded {
+        sheet: String,
+        path: String,
+        rows: usize,
+    },
+    /// An unknown type token was coerced to `FieldType::String`
+    TypeCoerced(TypeCoercion),
+    /// A field name already used in its sheet got a numeric suffix
+    FieldRenamed {
+        path: String,
+        column: usize,
+        name: String,
+        renamed: String,
+    },
+}
+
+impl Diagnostic {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Diagnostic::SheetLoaded { .. } => Severity::Info,
+            Diagnostic::TypeCoerced(_) | Diagnostic::FieldRenamed { .. } => Severity::Warning,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::SheetLoaded { sheet, path, rows } => {
+                write!(f, "Loaded {} from {} ({} rows)", sheet, path, rows)
+            }
+            Diagnostic::TypeCoerced(coercion) => write!(
+                f,
+                "Unknown type {:?} at column {} in {} coerced to string",
+                coercion.token, coercion.column, coercion.path
+            ),
+            Diagnostic::FieldRenamed {
+                path,
+                column,
+                name,
+                renamed,
+            } => write!(
+                f,
+                "Duplicate field {:?} at column {} in {} renamed to {:?}",
+                name, column, path, renamed
+            ),
+        }
+    }
+}
+
 pub type SchemaMap = HashMap<String, Schema>;
 
+/// Raw data rows (everything after the field types row) keyed by schema name
+pub type RowMap = HashMap<String, Vec<csv::StringRecord>>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,6 +224,7 @@ mod tests {
         let field = Field {
             name: "test_field".to_string(),
             field_type: FieldType::Image,
+            columns: vec![0],
         };
 
         assert_eq!(field.name, "test_field");
@@ -69,6 +236,7 @@ mod tests {
         let color_field = Field {
             name: "background_color".to_string(),
             field_type: FieldType::Color,
+            columns: vec![0],
         };
 
         assert_eq!(color_field.name, "background_color");
@@ -80,14 +248,17 @@ mod tests {
         let field1 = Field {
             name: "test_field".to_string(),
             field_type: FieldType::Image,
+            columns: vec![0],
         };
         let field2 = Field {
             name: "test_field".to_string(),
             field_type: FieldType::Image,
+            columns: vec![0],
         };
         let field3 = Field {
             name: "different_field".to_string(),
             field_type: FieldType::Image,
+            columns: vec![0],
         };
 
         assert_eq!(field1, field2);
@@ -99,10 +270,12 @@ mod tests {
         let field1 = Field {
             name: "id".to_string(),
             field_type: FieldType::Int32,
+            columns: vec![0],
         };
         let field2 = Field {
             name: "name".to_string(),
             field_type: FieldType::String,
+            columns: vec![1],
         };
 
         let schema1 = Schema {
@@ -121,4 +294,50 @@ mod tests {
         assert_eq!(schema1, schema2);
         assert_ne!(schema1, schema3);
     }
+
+    #[test]
+    fn test_integer_bounds() {
+        assert_eq!(FieldType::Byte.integer_bounds(), Some((0, 255)));
+        assert_eq!(FieldType::SByte.integer_bounds(), Some((-128, 127)));
+        assert_eq!(
+            FieldType::Custom("Item".to_string()).integer_bounds(),
+            Some((0, 4294967295))
+        );
+        assert_eq!(FieldType::Int64.integer_bounds(), None);
+        assert_eq!(FieldType::Float.integer_bounds(), None);
+    }
+
+    #[test]
+    fn test_field_leaves() {
+        let field = Field {
+            name: "baseParam".to_string(),
+            field_type: FieldType::Array(
+                Box::new(FieldType::Struct(vec![
+                    (
+                        "param".to_string(),
+                        FieldType::Custom("BaseParam".to_string()),
+                    ),
+                    ("value".to_string(), FieldType::Int16),
+                ])),
+                2,
+            ),
+            columns: vec![4, 5, 6, 7],
+        };
+
+        let leaves: Vec<(String, usize)> = field
+            .leaves()
+            .into_iter()
+            .map(|(name, _, column)| (name, column))
+            .collect();
+
+        assert_eq!(
+            leaves,
+            vec![
+                ("baseParam[0].param".to_string(), 4),
+                ("baseParam[0].value".to_string(), 5),
+                ("baseParam[1].param".to_string(), 6),
+                ("baseParam[1].value".to_string(), 7),
+            ]
+        );
+    }
 }
@@ -1,32 +1,40 @@
-use std::fs;
-use std::path::Path;
+use std::collections::HashMap;
 
-use super::error::SchemaError;
-use super::types::{FieldType, Schema, SchemaMap};
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Int64Representation, Schema, SchemaMap};
 
-pub struct TypeScriptGenerator;
+pub struct TypeScriptGenerator {
+    int64_representation: Int64Representation,
+    inline_depth: usize,
+    inline_fields: HashMap<String, Vec<String>>,
+}
 
 impl TypeScriptGenerator {
     pub fn new() -> Self {
-        Self
+        Self {
+            int64_representation: Int64Representation::default(),
+            inline_depth: 1,
+            inline_fields: HashMap::new(),
+        }
     }
 
-    /// Generate TypeScript interfaces for all schemas and save to file
-    pub fn generate_and_save<P: AsRef<Path>>(
-        &self,
-        schemas: &SchemaMap,
-        output_path: P,
-    ) -> Result<(), SchemaError> {
-        let path = output_path.as_ref();
+    /// Set the TypeScript type used for 64-bit integer fields
+    pub fn with_int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
 
-        // Create parent directories if they don't exist
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
+    /// Set how many levels of inlined references the interfaces describe
+    pub fn with_inline_depth(mut self, depth: usize) -> Self {
+        self.inline_depth = depth;
+        self
+    }
 
-        let content = self.generate_typescript_interfaces(schemas);
-        fs::write(path, content)?;
-        Ok(())
+    /// Describe references to the given sheet as inlined objects of the listed fields,
+    /// matching data extracted with the same `DataExporter` settings
+    pub fn with_inline_fields(mut self, sheet: impl Into<String>, fields: Vec<String>) -> Self {
+        self.inline_fields.insert(sheet.into(), fields);
+        self
     }
 
     /// Generate TypeScript interfaces for all schemas
@@ -45,9 +53,15 @@ impl TypeScriptGenerator {
         let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
         sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
 
+        // Add branded ID types and the denormalization helper
+        content.push_str(&self.generate_id_types(&sorted_schemas));
+        content.push('\n');
+        content.push_str(&self.generate_resolved_helper(&sorted_schemas));
+        content.push('\n');
+
         // Generate interface for each schema
         for schema in sorted_schemas {
-            content.push_str(&self.generate_interface(schema));
+            content.push_str(&self.generate_interface(schema, schemas));
             content.push('\n');
         }
 
@@ -55,13 +69,16 @@ impl TypeScriptGenerator {
     }
 
     /// Generate a single TypeScript interface from a schema
-    fn generate_interface(&self, schema: &Schema) -> String {
+    fn generate_interface(&self, schema: &Schema, schemas: &SchemaMap) -> String {
         let mut interface = String::new();
 
         interface.push_str(&format!("export interface {} {{\n", schema.name));
 
+        // The sheet itself starts the path so self-references stay keys, as in the data
+        let mut path = vec![schema.name.clone()];
         for field in &schema.fields {
-            let ts_type = self.field_type_to_typescript(&field.field_type);
+            let ts_type =
+                self.inlined_type(&field.field_type, schemas, self.inline_depth, &mut path);
             interface.push_str(&format!("  {}: {};\n", field.name, ts_type));
         }
 
@@ -69,6 +86,125 @@ impl TypeScriptGenerator {
         interface
     }
 
+    /// Generate a branded row key type for each sheet
+    fn generate_id_types(&self, schemas: &[&Schema]) -> String {
+        let mut types = String::new();
+
+        types.push_str("// Branded row key types referencing each sheet\n");
+        for schema in schemas {
+            types.push_str(&format!(
+                "export type {} = number & {{ __brand: '{}' }};\n",
+                Self::id_type_name(&schema.name),
+                schema.name
+            ));
+        }
+
+        types
+    }
+
+    /// Generate the sheet registry and `Resolved<T>` helper for denormalized rows
+    fn generate_resolved_helper(&self, schemas: &[&Schema]) -> String {
+        let mut helper = String::new();
+
+        helper.push_str("/** Row interface of each sheet, keyed by sheet name */\n");
+        helper.push_str("export interface SheetRows {\n");
+        for schema in schemas {
+            helper.push_str(&format!("  {}: {};\n", schema.name, schema.name));
+        }
+        helper.push_str("}\n\n");
+
+        helper.push_str("/** Replaces branded IDs in V with the rows they reference */\n");
+        helper.push_str(
+            "export type ResolveId<V> = V extends { __brand: infer S extends keyof SheetRows }\n",
+        );
+        helper.push_str("  ? SheetRows[S]\n");
+        helper.push_str("  : V extends readonly (infer E)[]\n");
+        helper.push_str("    ? ResolveId<E>[]\n");
+        helper.push_str("    : V extends object\n");
+        helper.push_str("      ? { [K in keyof V]: ResolveId<V[K]> }\n");
+        helper.push_str("      : V;\n\n");
+
+        helper.push_str(
+            "/** Denormalized form of a row: reference fields hold the referenced rows */\n",
+        );
+        helper.push_str("export type Resolved<T> = { [K in keyof T]: ResolveId<T[K]> };\n");
+
+        helper
+    }
+
+    /// Type of a value as extracted with the inline whitelist.
+    ///
+    /// Follows `DataExporter`: a whitelisted reference within the depth and not yet on
+    /// the path is an object of the listed fields, or its key when it is the `0`
+    /// "no reference" sentinel or no row matches.
+    fn inlined_type(
+        &self,
+        field_type: &FieldType,
+        schemas: &SchemaMap,
+        depth: usize,
+        path: &mut Vec<String>,
+    ) -> String {
+        match field_type {
+            FieldType::Array(element_type, _) => {
+                let element = self.inlined_type(element_type, schemas, depth, path);
+                if element.contains(" | ") {
+                    format!("({})[]", element)
+                } else {
+                    format!("{}[]", element)
+                }
+            }
+            FieldType::Struct(members) => {
+                let members: Vec<String> = members
+                    .iter()
+                    .map(|(name, member_type)| {
+                        format!(
+                            "{}: {}",
+                            name,
+                            self.inlined_type(member_type, schemas, depth, path)
+                        )
+                    })
+                    .collect();
+                format!("{{ {} }}", members.join("; "))
+            }
+            FieldType::Custom(target) => {
+                let (Some(fields), Some(schema)) =
+                    (self.inline_fields.get(target), schemas.get(target))
+                else {
+                    return self.field_type_to_typescript(field_type);
+                };
+                if depth == 0 || path.contains(target) {
+                    return self.field_type_to_typescript(field_type);
+                }
+
+                path.push(target.clone());
+                let members: Vec<String> = fields
+                    .iter()
+                    .filter_map(|name| schema.fields.iter().find(|field| &field.name == name))
+                    .map(|field| {
+                        format!(
+                            "{}: {}",
+                            field.name,
+                            self.inlined_type(&field.field_type, schemas, depth - 1, path)
+                        )
+                    })
+                    .collect();
+                path.pop();
+
+                format!(
+                    "{{ {} }} | {}",
+                    members.join("; "),
+                    Self::id_type_name(target)
+                )
+            }
+            _ => self.field_type_to_typescript(field_type),
+        }
+    }
+
+    /// Name of the branded ID type for a sheet
+    fn id_type_name(schema_name: &str) -> String {
+        format!("{}Id", schema_name)
+    }
+
     /// Generate special type definitions
     fn generate_special_types(&self) -> String {
         let mut types = String::new();
@@ -86,6 +222,9 @@ impl TypeScriptGenerator {
         types.push_str("/** Color code identifier */\n");
         types.push_str("export type ColorCode = number;\n\n");
 
+        types.push_str("/** 64-bit integer as a decimal string; decode with BigInt(value) */\n");
+        types.push_str("export type BigIntString = string;\n\n");
+
         types
     }
 
@@ -96,19 +235,56 @@ impl TypeScriptGenerator {
             FieldType::Int32 | FieldType::Uint32 | FieldType::Int16 | FieldType::Uint16 => {
                 "number".to_string()
             }
+            FieldType::Int64 | FieldType::Uint64 => match self.int64_representation {
+                // JSON has no bigint literal, so the data holds decimal strings
+                Int64Representation::BigInt => "BigIntString".to_string(),
+                Int64Representation::String => "string".to_string(),
+                Int64Representation::Number => "number".to_string(),
+                Int64Representation::Quad => "[number, number, number, number]".to_string(),
+            },
             FieldType::Byte | FieldType::SByte => "number".to_string(),
             FieldType::Float => "number".to_string(),
             FieldType::Bool => "boolean".to_string(),
-            FieldType::Bit(_) => "number".to_string(), // Bit values are treated as numbers
+            FieldType::Bit(_) => "boolean".to_string(), // Bit flags hold True/False
             FieldType::Image => "ImagePath".to_string(), // Use special type
-            FieldType::Row => "RowId".to_string(),     // Use special type
-            FieldType::Key => "KeyString".to_string(), // Use special type
+            FieldType::Row => "RowId".to_string(),      // Use special type
+            FieldType::Key => "KeyString".to_string(),  // Use special type
             FieldType::Color => "ColorCode".to_string(), // Use special type
-            FieldType::Custom(type_name) => type_name.clone(), // Reference to another interface
+            FieldType::Custom(type_name) => Self::id_type_name(type_name), // Branded row key
+            FieldType::Array(element_type, _) => {
+                format!("{}[]", self.field_type_to_typescript(element_type))
+            }
+            FieldType::Struct(members) => {
+                let members: Vec<String> = members
+                    .iter()
+                    .map(|(name, member_type)| {
+                        format!("{}: {}", name, self.field_type_to_typescript(member_type))
+                    })
+                    .collect();
+                format!("{{ {} }}", members.join("; "))
+            }
         }
     }
 }
 
+impl SchemaGenerator for TypeScriptGenerator {
+    fn name(&self) -> &'static str {
+        "ts"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["typescript"]
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "ts"
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_typescript_interfaces(schemas)
+    }
+}
+
 impl Default for TypeScriptGenerator {
     fn default() -> Self {
         Self::new()
@@ -128,26 +304,32 @@ mod tests {
                 Field {
                     name: "id".to_string(),
                     field_type: FieldType::Int32,
+                    columns: vec![0],
                 },
                 Field {
                     name: "name".to_string(),
                     field_type: FieldType::String,
+                    columns: vec![1],
                 },
                 Field {
                     name: "icon".to_string(),
                     field_type: FieldType::Image,
+                    columns: vec![2],
                 },
                 Field {
                     name: "level".to_string(),
                     field_type: FieldType::Byte,
+                    columns: vec![3],
                 },
                 Field {
                     name: "active".to_string(),
                     field_type: FieldType::Bool,
+                    columns: vec![4],
                 },
                 Field {
                     name: "category".to_string(),
                     field_type: FieldType::Custom("ItemCategory".to_string()),
+                    columns: vec![5],
                 },
             ],
         }
@@ -169,6 +351,10 @@ mod tests {
             generator.field_type_to_typescript(&FieldType::Bool),
             "boolean"
         );
+        assert_eq!(
+            generator.field_type_to_typescript(&FieldType::Bit(0x80)),
+            "boolean"
+        );
         assert_eq!(
             generator.field_type_to_typescript(&FieldType::Image),
             "ImagePath"
@@ -184,15 +370,65 @@ mod tests {
         );
         assert_eq!(
             generator.field_type_to_typescript(&FieldType::Custom("ItemCategory".to_string())),
-            "ItemCategory"
+            "ItemCategoryId"
+        );
+        assert_eq!(
+            generator.field_type_to_typescript(&FieldType::Array(
+                Box::new(FieldType::Custom("BaseParam".to_string())),
+                6
+            )),
+            "BaseParamId[]"
+        );
+        assert_eq!(
+            generator.field_type_to_typescript(&FieldType::Array(
+                Box::new(FieldType::Struct(vec![
+                    (
+                        "param".to_string(),
+                        FieldType::Custom("BaseParam".to_string())
+                    ),
+                    ("value".to_string(), FieldType::Int16),
+                ])),
+                6
+            )),
+            "{ param: BaseParamId; value: number }[]"
         );
     }
 
+    #[test]
+    fn test_int64_type_conversion() {
+        let generator = TypeScriptGenerator::new();
+        assert_eq!(
+            generator.field_type_to_typescript(&FieldType::Int64),
+            "BigIntString"
+        );
+
+        let cases = [
+            (Int64Representation::BigInt, "BigIntString"),
+            (Int64Representation::String, "string"),
+            (Int64Representation::Number, "number"),
+            (
+                Int64Representation::Quad,
+                "[number, number, number, number]",
+            ),
+        ];
+        for (representation, expected) in cases {
+            let generator = TypeScriptGenerator::new().with_int64_representation(representation);
+            assert_eq!(
+                generator.field_type_to_typescript(&FieldType::Int64),
+                expected
+            );
+            assert_eq!(
+                generator.field_type_to_typescript(&FieldType::Uint64),
+                expected
+            );
+        }
+    }
+
     #[test]
     fn test_interface_generation() {
         let generator = TypeScriptGenerator::new();
         let schema = create_test_schema();
-        let interface = generator.generate_interface(&schema);
+        let interface = generator.generate_interface(&schema, &SchemaMap::new());
 
         let expected = r#"export interface TestItem {
   id: number;
@@ -200,7 +436,7 @@ mod tests {
   icon: ImagePath;
   level: number;
   active: boolean;
-  category: ItemCategory;
+  category: ItemCategoryId;
 }
 "#;
 
@@ -222,10 +458,12 @@ mod tests {
                 Field {
                     name: "id".to_string(),
                     field_type: FieldType::Byte,
+                    columns: vec![0],
                 },
                 Field {
                     name: "name".to_string(),
                     field_type: FieldType::String,
+                    columns: vec![1],
                 },
             ],
         };
@@ -235,7 +473,14 @@ mod tests {
 
         assert!(typescript.contains("export interface ItemCategory"));
         assert!(typescript.contains("export interface TestItem"));
-        assert!(typescript.contains("category: ItemCategory;"));
+        assert!(typescript.contains("category: ItemCategoryId;"));
+        assert!(
+            typescript
+                .contains("export type ItemCategoryId = number & { __brand: 'ItemCategory' };")
+        );
+        assert!(typescript.contains("export type TestItemId = number & { __brand: 'TestItem' };"));
+        assert!(typescript.contains("  ItemCategory: ItemCategory;\n  TestItem: TestItem;\n"));
+        assert!(typescript.contains("export type Resolved<T>"));
         assert!(typescript.contains("icon: ImagePath;"));
         assert!(typescript.contains("export type ImagePath = string;"));
         assert!(typescript.contains("export type RowId = number;"));
@@ -244,6 +489,67 @@ mod tests {
         assert!(typescript.starts_with("// Generated TypeScript interfaces"));
     }
 
+    #[test]
+    fn test_inlined_references_follow_data_shape() {
+        let custom = |name: &str| FieldType::Custom(name.to_string());
+        let field = |name: &str, field_type: FieldType| Field {
+            name: name.to_string(),
+            field_type,
+            columns: vec![0],
+        };
+        let mut schemas = SchemaMap::new();
+        schemas.insert(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: vec![
+                    field("id", FieldType::Int32),
+                    field("category", custom("ItemCategory")),
+                    field(
+                        "categories",
+                        FieldType::Array(Box::new(custom("ItemCategory")), 2),
+                    ),
+                    field("itemRepair", custom("Item")),
+                ],
+            },
+        );
+        schemas.insert(
+            "ItemCategory".to_string(),
+            Schema {
+                name: "ItemCategory".to_string(),
+                fields: vec![
+                    field("id", FieldType::Byte),
+                    field("name", FieldType::String),
+                    field("parent", custom("ItemCategory")),
+                ],
+            },
+        );
+
+        let generator = TypeScriptGenerator::new()
+            .with_inline_depth(2)
+            .with_inline_fields("Item", vec!["id".to_string()])
+            .with_inline_fields(
+                "ItemCategory",
+                vec!["name".to_string(), "parent".to_string()],
+            );
+        let interface = generator.generate_interface(&schemas["Item"], &schemas);
+
+        // Missing rows keep their key; cycles on the path stay keys as in the data
+        assert!(
+            interface.contains(
+                "  category: { name: string; parent: ItemCategoryId } | ItemCategoryId;\n"
+            )
+        );
+        assert!(interface.contains(
+            "  categories: ({ name: string; parent: ItemCategoryId } | ItemCategoryId)[];\n"
+        ));
+        assert!(interface.contains("  itemRepair: ItemId;\n"));
+
+        // Without a whitelist the interfaces keep plain keys
+        let plain = TypeScriptGenerator::new().generate_interface(&schemas["Item"], &schemas);
+        assert!(plain.contains("  category: ItemCategoryId;\n"));
+    }
+
     #[test]
     fn test_color_type_typescript_generation() {
         let generator = TypeScriptGenerator::new();
@@ -256,18 +562,22 @@ mod tests {
                 Field {
                     name: "id".to_string(),
                     field_type: FieldType::Int32,
+                    columns: vec![0],
                 },
                 Field {
                     name: "name".to_string(),
                     field_type: FieldType::String,
+                    columns: vec![1],
                 },
                 Field {
                     name: "backgroundColor".to_string(),
                     field_type: FieldType::Color,
+                    columns: vec![2],
                 },
                 Field {
                     name: "textColor".to_string(),
                     field_type: FieldType::Color,
+                    columns: vec![3],
                 },
             ],
         };
@@ -278,11 +588,11 @@ mod tests {
         // Verify ColorCode type definition is generated
         assert!(typescript.contains("export type ColorCode = number;"));
         assert!(typescript.contains("/** Color code identifier */"));
-        
+
         // Verify Color fields are properly typed
         assert!(typescript.contains("backgroundColor: ColorCode;"));
         assert!(typescript.contains("textColor: ColorCode;"));
-        
+
         // Verify interface structure
         assert!(typescript.contains("export interface ColoredItem"));
         assert!(typescript.contains("id: number;"));
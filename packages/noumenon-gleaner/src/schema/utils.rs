@@ -1,5 +1,7 @@
 use super::error::SchemaError;
+use super::types::FieldType;
 use crate::constants::{BASIC_TYPES, CUSTOM_TYPE_PATTERNS, SPECIAL_TYPES};
+use std::collections::HashSet;
 use std::path::Path;
 
 /// Determines if a type string represents a likely custom type
@@ -49,6 +51,131 @@ pub fn parse_bit_value(bit_str: &str) -> Result<u8, SchemaError> {
         })
 }
 
+/// Splits an indexed field description (e.g., "BaseParam[0]") into its base name and index
+pub fn parse_indexed_name(description: &str) -> Option<(&str, usize)> {
+    let (base, index) = description.strip_suffix(']')?.rsplit_once('[')?;
+    if base.is_empty() {
+        return None;
+    }
+    index.parse().ok().map(|index| (base, index))
+}
+
+/// Splits a qualified field description (e.g., "Price{Mid}") into its base name and qualifier
+pub fn parse_qualified_name(description: &str) -> Option<(&str, &str)> {
+    let (base, qualifier) = description.strip_suffix('}')?.split_once('{')?;
+    let is_plain = |part: &str| !part.is_empty() && !part.contains(['{', '}', '[', ']']);
+    (is_plain(base) && is_plain(qualifier)).then_some((base, qualifier))
+}
+
+/// Splits a field description into its CamelCase words and curly-brace qualifiers,
+/// e.g. "BaseParamValue{Special}" becomes (["Base", "Param", "Value"], "{Special}")
+pub fn split_qualified_words(description: &str) -> (Vec<&str>, String) {
+    let mut words = Vec::new();
+    let mut qualifiers = String::new();
+    let mut rest = description;
+
+    while !rest.is_empty() {
+        if let Some(after_open) = rest.strip_prefix('{') {
+            let end = after_open.find('}').map_or(after_open.len(), |i| i + 1);
+            qualifiers.push('{');
+            qualifiers.push_str(&after_open[..end]);
+            rest = &after_open[end..];
+            continue;
+        }
+
+        // A word runs until the next uppercase letter or qualifier
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_uppercase() || *c == '{')
+            .map_or(rest.len(), |(i, _)| i);
+        words.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+
+    (words, qualifiers)
+}
+
+/// Converts a camelCase name to snake_case, keeping acronyms together
+/// (e.g., "itemUICategory" becomes "item_ui_category")
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch.is_ascii_uppercase() {
+            let after_word =
+                i > 0 && (chars[i - 1].is_ascii_lowercase() || chars[i - 1].is_ascii_digit());
+            // Last capital of an acronym starts the next word
+            let ends_acronym = i > 0
+                && chars[i - 1].is_ascii_uppercase()
+                && chars
+                    .get(i + 1)
+                    .is_some_and(|next| next.is_ascii_lowercase());
+            if after_word || ends_acronym {
+                result.push('_');
+            }
+            result.push(ch.to_ascii_lowercase());
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+/// Converts a camelCase name to PascalCase (e.g., "baseParam" becomes "BaseParam")
+pub fn to_pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Converts a PascalCase name to camelCase (e.g., "BaseParam" becomes "baseParam")
+pub fn to_camel_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Members of the record type held by a field, looking through arrays
+pub fn record_members(field_type: &FieldType) -> Option<&[(String, FieldType)]> {
+    match field_type {
+        FieldType::Struct(members) => Some(members),
+        FieldType::Array(element_type, _) => record_members(element_type),
+        _ => None,
+    }
+}
+
+/// Names the type generated for a record field (e.g., "ItemBaseParam" for `Item.baseParam`).
+///
+/// See [`unique_type_name`] for how collisions with `taken` are resolved.
+pub fn record_type_name(parent: &str, field_name: &str, taken: &mut HashSet<String>) -> String {
+    unique_type_name(format!("{}{}", parent, to_pascal_case(field_name)), taken)
+}
+
+/// Returns `base`, or when `taken` already holds it, `base` with a "Record" suffix and
+/// then a number ("ItemLevelRecord2"). The chosen name is added to `taken`.
+pub fn unique_type_name(base: String, taken: &mut HashSet<String>) -> String {
+    let mut name = base.clone();
+    let mut counter = 1;
+    while taken.contains(&name) {
+        name = if counter == 1 {
+            format!("{}Record", base)
+        } else {
+            format!("{}Record{}", base, counter)
+        };
+        counter += 1;
+    }
+
+    taken.insert(name.clone());
+    name
+}
+
 /// Extracts schema name from file path
 pub fn extract_schema_name_from_path(path: &std::path::Path) -> Option<String> {
     path.file_stem()
@@ -159,6 +286,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_indexed_name() {
+        assert_eq!(parse_indexed_name("BaseParam[0]"), Some(("BaseParam", 0)));
+        assert_eq!(
+            parse_indexed_name("BaseParamValue{Special}[5]"),
+            Some(("BaseParamValue{Special}", 5))
+        );
+        assert_eq!(parse_indexed_name("BaseParam"), None);
+        assert_eq!(parse_indexed_name("BaseParam[x]"), None);
+        assert_eq!(parse_indexed_name("[0]"), None);
+    }
+
+    #[test]
+    fn test_parse_qualified_name() {
+        assert_eq!(parse_qualified_name("Price{Mid}"), Some(("Price", "Mid")));
+        assert_eq!(parse_qualified_name("Model{Main}"), Some(("Model", "Main")));
+        assert_eq!(parse_qualified_name("Price"), None);
+        assert_eq!(parse_qualified_name("{Mid}"), None);
+        assert_eq!(parse_qualified_name("Price{}"), None);
+        assert_eq!(parse_qualified_name("BaseParam{Special}[0]"), None);
+    }
+
+    #[test]
+    fn test_split_qualified_words() {
+        assert_eq!(
+            split_qualified_words("BaseParamValue{Special}"),
+            (vec!["Base", "Param", "Value"], "{Special}".to_string())
+        );
+        assert_eq!(
+            split_qualified_words("BaseParam"),
+            (vec!["Base", "Param"], String::new())
+        );
+        assert_eq!(
+            split_qualified_words("Price{Mid}"),
+            (vec!["Price"], "{Mid}".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("id"), "id");
+        assert_eq!(to_snake_case("startsWithVowel"), "starts_with_vowel");
+        assert_eq!(to_snake_case("itemUICategory"), "item_ui_category");
+        assert_eq!(to_snake_case("field5"), "field5");
+        assert_eq!(to_snake_case("model2Main"), "model2_main");
+        assert_eq!(to_pascal_case("baseParam"), "BaseParam");
+        assert_eq!(to_camel_case("BaseParam"), "baseParam");
+    }
+
+    #[test]
+    fn test_record_members() {
+        let members = vec![
+            ("param".to_string(), FieldType::Int32),
+            ("value".to_string(), FieldType::Int16),
+        ];
+        let record = FieldType::Struct(members.clone());
+
+        assert_eq!(record_members(&record), Some(members.as_slice()));
+        assert_eq!(
+            record_members(&FieldType::Array(Box::new(record.clone()), 6)),
+            Some(members.as_slice())
+        );
+        assert_eq!(record_members(&FieldType::Int32), None);
+    }
+
+    #[test]
+    fn test_record_type_name() {
+        let mut taken = HashSet::from(["ItemLevel".to_string(), "ItemModelRecord".to_string()]);
+
+        assert_eq!(
+            record_type_name("Item", "baseParam", &mut taken),
+            "ItemBaseParam"
+        );
+        // Sheet names, generated records and existing `<Name>Record` types all collide
+        assert_eq!(
+            record_type_name("Item", "level", &mut taken),
+            "ItemLevelRecord"
+        );
+        assert_eq!(
+            record_type_name("Item", "level", &mut taken),
+            "ItemLevelRecord2"
+        );
+        assert_eq!(
+            record_type_name("Item", "baseParam", &mut taken),
+            "ItemBaseParamRecord"
+        );
+        taken.insert("ItemModel".to_string());
+        assert_eq!(
+            record_type_name("Item", "model", &mut taken),
+            "ItemModelRecord2"
+        );
+    }
+
     #[test]
     fn test_find_missing_files_in_types() {
         // Create a mock StringRecord
@@ -203,20 +423,21 @@ mod tests {
     #[test]
     fn test_mixed_types_with_color() {
         // Create a mock StringRecord mixing special types with custom types
-        let record = csv::StringRecord::from(vec!["str", "Color", "Image", "CustomType", "Row", "Key"]);
+        let record =
+            csv::StringRecord::from(vec!["str", "Color", "Image", "CustomType", "Row", "Key"]);
         let base_dir = Path::new(".");
 
         let missing_files = find_missing_files_in_types(&record, base_dir);
 
         // Only CustomType should be detected as missing
         assert!(missing_files.contains(&"CustomType".to_string()));
-        
+
         // Special types should not be treated as missing
         assert!(!missing_files.contains(&"Color".to_string()));
         assert!(!missing_files.contains(&"Image".to_string()));
         assert!(!missing_files.contains(&"Row".to_string()));
         assert!(!missing_files.contains(&"Key".to_string()));
-        
+
         // Basic type should not be treated as missing
         assert!(!missing_files.contains(&"str".to_string()));
     }
@@ -0,0 +1,742 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use serde_json::{Value as JsonValue, json};
+
+use super::error::SchemaError;
+use super::types::{FieldType, RowMap, Schema, SchemaMap};
+use crate::constants::ZERO_SENTINEL;
+
+/// Reference value with no matching row in the target sheet
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingReference {
+    pub sheet: String,
+    pub row_key: String,
+    pub column: String,
+    pub target: String,
+    pub missing_key: String,
+}
+
+/// Reference counts for one field pointing at a target sheet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReferenceEdge {
+    pub sheet: String,
+    pub field: String,
+    pub target: String,
+    pub checked: usize,
+    pub sentinels: usize,
+    pub dangling: usize,
+}
+
+/// Result of a referential integrity pass over the loaded data
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReferenceReport {
+    pub edges: Vec<ReferenceEdge>,
+    pub dangling: Vec<DanglingReference>,
+}
+
+impl ReferenceReport {
+    pub fn is_valid(&self) -> bool {
+        self.dangling.is_empty()
+    }
+}
+
+pub struct ReferenceValidator {
+    allow_zero_sentinel: bool,
+}
+
+impl ReferenceValidator {
+    pub fn new() -> Self {
+        Self {
+            allow_zero_sentinel: true,
+        }
+    }
+
+    /// Set whether a `0` key is accepted as "no reference" even without a row 0
+    pub fn with_zero_sentinel(mut self, allow_zero_sentinel: bool) -> Self {
+        self.allow_zero_sentinel = allow_zero_sentinel;
+        self
+    }
+
+    /// Check every custom-type reference against the keys of its target sheet
+    pub fn validate(&self, schemas: &SchemaMap, rows: &RowMap) -> ReferenceReport {
+        let keys: HashMap<&str, HashSet<&str>> = rows
+            .iter()
+            .map(|(sheet, records)| {
+                let keys = records
+                    .iter()
+                    .map(|record| record.get(0).unwrap_or_default().trim())
+                    .collect();
+                (sheet.as_str(), keys)
+            })
+            .collect();
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut edges: BTreeMap<(String, String, String), ReferenceEdge> = BTreeMap::new();
+        let mut dangling = Vec::new();
+
+        for schema in sorted_schemas {
+            let records = rows.get(&schema.name).map(Vec::as_slice).unwrap_or(&[]);
+            for record in records {
+                for field in &schema.fields {
+                    for (column, field_type, index) in field.leaves() {
+                        let FieldType::Custom(target) = field_type else {
+                            continue;
+                        };
+                        let key = record.get(index).unwrap_or_default().trim();
+                        let edge = edges
+                            .entry((schema.name.clone(), field.name.clone(), target.to_string()))
+                            .or_insert_with(|| ReferenceEdge {
+                                sheet: schema.name.clone(),
+                                field: field.name.clone(),
+                                target: target.to_string(),
+                                ..Default::default()
+                            });
+                        edge.checked += 1;
+
+                        let target_keys = keys.get(target.as_str());
+                        if target_keys.is_some_and(|target_keys| target_keys.contains(key)) {
+                            continue;
+                        }
+                        if self.allow_zero_sentinel && key == ZERO_SENTINEL {
+                            edge.sentinels += 1;
+                            continue;
+                        }
+
+                        edge.dangling += 1;
+                        dangling.push(DanglingReference {
+                            sheet: schema.name.clone(),
+                            row_key: record.get(0).unwrap_or_default().to_string(),
+                            column,
+                            target: target.to_string(),
+                            missing_key: key.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        ReferenceReport {
+            edges: edges.into_values().collect(),
+            dangling,
+        }
+    }
+}
+
+impl Default for ReferenceValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Kind of problem found while validating data rows against their schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueKind {
+    ColumnCount,
+    InvalidValue,
+    NonBooleanBit,
+    DuplicateKey,
+    DanglingReference,
+}
+
+impl IssueKind {
+    pub const ALL: [IssueKind; 5] = [
+        IssueKind::ColumnCount,
+        IssueKind::InvalidValue,
+        IssueKind::NonBooleanBit,
+        IssueKind::DuplicateKey,
+        IssueKind::DanglingReference,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueKind::ColumnCount => "column-count",
+            IssueKind::InvalidValue => "invalid-value",
+            IssueKind::NonBooleanBit => "non-boolean-bit",
+            IssueKind::DuplicateKey => "duplicate-key",
+            IssueKind::DanglingReference => "dangling-reference",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    pub kind: IssueKind,
+    pub sheet: String,
+    pub row_key: String,
+    pub column: Option<String>,
+    pub value: Option<String>,
+    pub message: String,
+}
+
+/// Result of checking every data row of every loaded sheet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub sheets: usize,
+    pub rows: usize,
+    pub issues: Vec<ValidationIssue>,
+    /// Per-edge reference counts, present only when references were checked
+    pub references: Option<ReferenceReport>,
+    /// Errors that kept the sheets from loading (`SchemaError::to_json_list`)
+    pub errors: Vec<JsonValue>,
+}
+
+impl ValidationReport {
+    /// Report for sheets that could not be loaded, so no rows were checked
+    pub fn from_build_error(error: &SchemaError) -> Self {
+        Self {
+            errors: error.to_json_list(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty() && self.errors.is_empty()
+    }
+
+    pub fn count(&self, kind: IssueKind) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.kind == kind)
+            .count()
+    }
+
+    /// Machine-readable report for pipelines
+    pub fn to_json(&self) -> JsonValue {
+        let counts: serde_json::Map<String, JsonValue> = IssueKind::ALL
+            .iter()
+            .map(|kind| (kind.as_str().to_string(), json!(self.count(*kind))))
+            .collect();
+        let issues: Vec<JsonValue> = self
+            .issues
+            .iter()
+            .map(|issue| {
+                json!({
+                    "kind": issue.kind.as_str(),
+                    "sheet": issue.sheet,
+                    "row_key": issue.row_key,
+                    "column": issue.column,
+                    "value": issue.value,
+                    "message": issue.message,
+                })
+            })
+            .collect();
+
+        let mut report = json!({
+            "valid": self.is_valid(),
+            "sheets": self.sheets,
+            "rows": self.rows,
+            "counts": counts,
+            "issues": issues,
+            "errors": self.errors,
+        });
+        if let Some(references) = &self.references {
+            report["references"] = references
+                .edges
+                .iter()
+                .map(|edge| {
+                    json!({
+                        "sheet": edge.sheet,
+                        "field": edge.field,
+                        "target": edge.target,
+                        "checked": edge.checked,
+                        "sentinels": edge.sentinels,
+                        "dangling": edge.dangling,
+                    })
+                })
+                .collect();
+        }
+        report
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Validated {} sheet(s), {} row(s)",
+            self.sheets, self.rows
+        )?;
+
+        if let Some(references) = &self.references {
+            for edge in &references.edges {
+                writeln!(
+                    f,
+                    "  {}.{} -> {}: {} checked, {} sentinel(s), {} dangling",
+                    edge.sheet,
+                    edge.field,
+                    edge.target,
+                    edge.checked,
+                    edge.sentinels,
+                    edge.dangling
+                )?;
+            }
+        }
+
+        for error in &self.errors {
+            writeln!(
+                f,
+                "Error: {}",
+                error["message"].as_str().unwrap_or_default()
+            )?;
+        }
+
+        if self.is_valid() {
+            return writeln!(f, "No issues found");
+        }
+        if self.issues.is_empty() {
+            return Ok(());
+        }
+
+        let counts: Vec<String> = IssueKind::ALL
+            .iter()
+            .filter(|kind| self.count(**kind) > 0)
+            .map(|kind| format!("{}: {}", kind.as_str(), self.count(*kind)))
+            .collect();
+        writeln!(f, "{} issue(s) ({})", self.issues.len(), counts.join(", "))?;
+
+        for issue in &self.issues {
+            write!(
+                f,
+                "  - [{}] {} (row: {}",
+                issue.kind.as_str(),
+                issue.sheet,
+                issue.row_key
+            )?;
+            if let Some(column) = &issue.column {
+                write!(f, ", column: {}", column)?;
+            }
+            write!(f, ")")?;
+            if let Some(value) = &issue.value {
+                write!(f, " {:?}", value)?;
+            }
+            writeln!(f, ": {}", issue.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct DataValidator {
+    references: Option<ReferenceValidator>,
+}
+
+impl DataValidator {
+    pub fn new() -> Self {
+        Self { references: None }
+    }
+
+    /// Also check references with the given validator and report dangling ones as issues
+    pub fn with_references(mut self, validator: ReferenceValidator) -> Self {
+        self.references = Some(validator);
+        self
+    }
+
+    /// Check column counts, value types and key uniqueness of every data row
+    pub fn validate(&self, schemas: &SchemaMap, rows: &RowMap) -> ValidationReport {
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut report = ValidationReport {
+            sheets: sorted_schemas.len(),
+            ..Default::default()
+        };
+
+        for schema in sorted_schemas {
+            let records = rows.get(&schema.name).map(Vec::as_slice).unwrap_or(&[]);
+            report.rows += records.len();
+            Self::validate_sheet(schema, records, &mut report.issues);
+        }
+
+        if let Some(validator) = &self.references {
+            let references = validator.validate(schemas, rows);
+            report
+                .issues
+                .extend(references.dangling.iter().map(|reference| ValidationIssue {
+                    kind: IssueKind::DanglingReference,
+                    sheet: reference.sheet.clone(),
+                    row_key: reference.row_key.clone(),
+                    column: Some(reference.column.clone()),
+                    value: Some(reference.missing_key.clone()),
+                    message: format!("key not found in {}", reference.target),
+                }));
+            report.references = Some(references);
+        }
+
+        report
+    }
+
+    fn validate_sheet(
+        schema: &Schema,
+        records: &[csv::StringRecord],
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let expected = schema.column_count();
+        let mut seen_keys: HashMap<&str, usize> = HashMap::new();
+
+        for (index, record) in records.iter().enumerate() {
+            let row_key = record.get(0).unwrap_or_default();
+            let issue =
+                |kind, column: Option<&str>, value: Option<&str>, message| ValidationIssue {
+                    kind,
+                    sheet: schema.name.clone(),
+                    row_key: row_key.to_string(),
+                    column: column.map(str::to_string),
+                    value: value.map(str::to_string),
+                    message,
+                };
+
+            // Every duplicate points back at the first row using the key
+            let first = *seen_keys.entry(row_key.trim()).or_insert(index);
+            if first != index {
+                issues.push(issue(
+                    IssueKind::DuplicateKey,
+                    None,
+                    Some(row_key),
+                    format!("key already used by data row {}", first + 1),
+                ));
+            }
+
+            // Value positions are unreliable when the column count is off
+            if record.len() != expected {
+                issues.push(issue(
+                    IssueKind::ColumnCount,
+                    None,
+                    None,
+                    format!("expected {} columns, found {}", expected, record.len()),
+                ));
+                continue;
+            }
+
+            for field in &schema.fields {
+                for (column, field_type, position) in field.leaves() {
+                    let raw = record.get(position).unwrap_or_default();
+                    if let Err(reason) = field_type.parse_value(raw) {
+                        let kind = match field_type {
+                            FieldType::Bit(_) => IssueKind::NonBooleanBit,
+                            _ => IssueKind::InvalidValue,
+                        };
+                        issues.push(issue(kind, Some(&column), Some(raw), reason));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for DataValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn create_test_data() -> (SchemaMap, RowMap) {
+        let mut schemas = SchemaMap::new();
+        schemas.insert(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: vec![
+                    Field {
+                        name: "id".to_string(),
+                        field_type: FieldType::Int32,
+                        columns: vec![0],
+                    },
+                    Field {
+                        name: "category".to_string(),
+                        field_type: FieldType::Custom("ItemCategory".to_string()),
+                        columns: vec![1],
+                    },
+                    Field {
+                        name: "baseParam".to_string(),
+                        field_type: FieldType::Array(
+                            Box::new(FieldType::Struct(vec![
+                                (
+                                    "param".to_string(),
+                                    FieldType::Custom("BaseParam".to_string()),
+                                ),
+                                ("value".to_string(), FieldType::Int16),
+                            ])),
+                            2,
+                        ),
+                        columns: vec![2, 3, 4, 5],
+                    },
+                ],
+            },
+        );
+        schemas.insert(
+            "ItemCategory".to_string(),
+            Schema {
+                name: "ItemCategory".to_string(),
+                fields: vec![Field {
+                    name: "id".to_string(),
+                    field_type: FieldType::Int32,
+                    columns: vec![0],
+                }],
+            },
+        );
+        schemas.insert(
+            "BaseParam".to_string(),
+            Schema {
+                name: "BaseParam".to_string(),
+                fields: vec![Field {
+                    name: "id".to_string(),
+                    field_type: FieldType::Int32,
+                    columns: vec![0],
+                }],
+            },
+        );
+
+        let mut rows = RowMap::new();
+        rows.insert(
+            "Item".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["1", "3", "12", "5", "0", "0"]),
+                csv::StringRecord::from(vec!["2", "7", "12", "5", "99", "1"]),
+            ],
+        );
+        rows.insert(
+            "ItemCategory".to_string(),
+            vec![csv::StringRecord::from(vec!["3"])],
+        );
+        rows.insert(
+            "BaseParam".to_string(),
+            vec![csv::StringRecord::from(vec!["12"])],
+        );
+
+        (schemas, rows)
+    }
+
+    #[test]
+    fn test_reports_dangling_references() {
+        let (schemas, rows) = create_test_data();
+        let report = ReferenceValidator::new().validate(&schemas, &rows);
+
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.dangling,
+            vec![
+                DanglingReference {
+                    sheet: "Item".to_string(),
+                    row_key: "2".to_string(),
+                    column: "category".to_string(),
+                    target: "ItemCategory".to_string(),
+                    missing_key: "7".to_string(),
+                },
+                DanglingReference {
+                    sheet: "Item".to_string(),
+                    row_key: "2".to_string(),
+                    column: "baseParam[1].param".to_string(),
+                    target: "BaseParam".to_string(),
+                    missing_key: "99".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_counts_per_edge() {
+        let (schemas, rows) = create_test_data();
+        let report = ReferenceValidator::new().validate(&schemas, &rows);
+
+        assert_eq!(
+            report.edges,
+            vec![
+                ReferenceEdge {
+                    sheet: "Item".to_string(),
+                    field: "baseParam".to_string(),
+                    target: "BaseParam".to_string(),
+                    checked: 4,
+                    sentinels: 1,
+                    dangling: 1,
+                },
+                ReferenceEdge {
+                    sheet: "Item".to_string(),
+                    field: "category".to_string(),
+                    target: "ItemCategory".to_string(),
+                    checked: 2,
+                    sentinels: 0,
+                    dangling: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zero_sentinel_can_be_disallowed() {
+        let (schemas, rows) = create_test_data();
+        let report = ReferenceValidator::new()
+            .with_zero_sentinel(false)
+            .validate(&schemas, &rows);
+
+        let missing: Vec<(&str, &str)> = report
+            .dangling
+            .iter()
+            .map(|d| (d.column.as_str(), d.missing_key.as_str()))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                ("baseParam[1].param", "0"),
+                ("category", "7"),
+                ("baseParam[1].param", "99"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_rows() {
+        let (schemas, mut rows) = create_test_data();
+        rows.insert(
+            "Item".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["1", "3", "12", "5", "0", "0"]),
+                csv::StringRecord::from(vec!["1", "3", "12", "abc", "0", "0"]),
+                csv::StringRecord::from(vec!["2", "3", "12"]),
+            ],
+        );
+
+        let report = DataValidator::new().validate(&schemas, &rows);
+
+        assert_eq!(report.sheets, 3);
+        assert_eq!(report.rows, 5);
+        let issues: Vec<(IssueKind, &str, Option<&str>)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.kind, issue.row_key.as_str(), issue.column.as_deref()))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (IssueKind::DuplicateKey, "1", None),
+                (IssueKind::InvalidValue, "1", Some("baseParam[0].value")),
+                (IssueKind::ColumnCount, "2", None),
+            ]
+        );
+        assert!(report.references.is_none());
+    }
+
+    #[test]
+    fn test_duplicate_keys_point_at_first_row() {
+        let (schemas, mut rows) = create_test_data();
+        rows.insert(
+            "Item".to_string(),
+            vec![
+                csv::StringRecord::from(vec!["1", "3", "12", "5", "0", "0"]),
+                csv::StringRecord::from(vec!["1", "3", "12", "5", "0", "0"]),
+                csv::StringRecord::from(vec![" 1", "3", "12", "5", "0", "0"]),
+            ],
+        );
+
+        let report = DataValidator::new().validate(&schemas, &rows);
+
+        let messages: Vec<&str> = report
+            .issues
+            .iter()
+            .filter(|issue| issue.kind == IssueKind::DuplicateKey)
+            .map(|issue| issue.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "key already used by data row 1",
+                "key already used by data row 1"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_bits_and_references() {
+        let (mut schemas, rows) = create_test_data();
+        schemas.get_mut("ItemCategory").unwrap().fields[0].field_type = FieldType::Bit(1);
+
+        let report = DataValidator::new()
+            .with_references(ReferenceValidator::new())
+            .validate(&schemas, &rows);
+
+        let kinds: Vec<IssueKind> = report.issues.iter().map(|issue| issue.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                IssueKind::NonBooleanBit,
+                IssueKind::DanglingReference,
+                IssueKind::DanglingReference,
+            ]
+        );
+        assert_eq!(report.count(IssueKind::DanglingReference), 2);
+        assert_eq!(report.references.as_ref().unwrap().edges.len(), 2);
+    }
+
+    #[test]
+    fn test_validation_report_formats() {
+        let (schemas, rows) = create_test_data();
+        let report = DataValidator::new()
+            .with_references(ReferenceValidator::new())
+            .validate(&schemas, &rows);
+
+        let json = report.to_json();
+        assert_eq!(json["valid"], json!(false));
+        assert_eq!(json["counts"]["dangling-reference"], json!(2));
+        assert_eq!(json["issues"][0]["row_key"], json!("2"));
+        assert_eq!(json["errors"], json!([]));
+        assert_eq!(json["issues"][0]["value"], json!("7"));
+        assert_eq!(json["references"][0]["field"], json!("baseParam"));
+
+        let human = report.to_string();
+        assert!(human.starts_with("Validated 3 sheet(s), 4 row(s)\n"));
+        assert!(human.contains(
+            "  - [dangling-reference] Item (row: 2, column: category) \"7\": key not found in ItemCategory\n"
+        ));
+    }
+
+    #[test]
+    fn test_build_error_report_lists_every_error() {
+        let error = SchemaError::Multiple {
+            errors: vec![
+                SchemaError::FileNotFound {
+                    path: "ItemLevel.csv".to_string(),
+                    source_file: "Item.csv".to_string(),
+                },
+                SchemaError::MissingCsvHeader {
+                    header: "key".to_string(),
+                    path: "Rarity.csv".to_string(),
+                },
+            ],
+        };
+
+        let report = ValidationReport::from_build_error(&error);
+        assert!(!report.is_valid());
+
+        // Same shape as a report of checked rows
+        let json = report.to_json();
+        assert_eq!(json["valid"], json!(false));
+        assert_eq!(json["sheets"], json!(0));
+        assert_eq!(json["issues"], json!([]));
+        assert_eq!(
+            json["errors"],
+            json!([
+                {
+                    "message": "File not found: ItemLevel.csv (referenced from: Item.csv)",
+                    "kind": "file-not-found",
+                    "path": "ItemLevel.csv",
+                    "source_file": "Item.csv",
+                },
+                {
+                    "message": "Required CSV header not found: key in file: Rarity.csv",
+                    "kind": "missing-csv-header",
+                    "header": "key",
+                    "path": "Rarity.csv",
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use serde_json::Value as JsonValue;
+
+use super::types::{FieldType, Int64Representation};
+
+/// Typed value of a single data cell
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Int(i64),
+    Int64(i64),
+    Uint64(u64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Value {
+    /// Convert to the JSON representation used by extracted data files
+    pub fn to_json(&self, int64: Int64Representation) -> JsonValue {
+        match self {
+            Value::String(value) => JsonValue::String(value.clone()),
+            Value::Int(value) => JsonValue::from(*value),
+            Value::Int64(value) => int64_to_json(*value as u64, value.to_string(), int64),
+            Value::Uint64(value) => int64_to_json(*value, value.to_string(), int64),
+            // Non-finite floats are rejected while parsing, so this never yields null
+            Value::Float(value) => JsonValue::from(*value),
+            Value::Bool(value) => JsonValue::Bool(*value),
+        }
+    }
+}
+
+impl FieldType {
+    /// Parse a raw CSV cell into a typed value.
+    ///
+    /// Returns the reason as an error so callers can attach sheet, row and column context.
+    pub fn parse_value(&self, raw: &str) -> Result<Value, String> {
+        match self {
+            // Strings and keys are preserved as-is
+            FieldType::String | FieldType::Key => Ok(Value::String(raw.to_string())),
+            // Image IDs keep their original text (e.g. leading zeros) but must be numeric
+            FieldType::Image => {
+                parse_integer(raw, 0, u32::MAX.into(), "Image")?;
+                Ok(Value::String(raw.to_string()))
+            }
+            FieldType::Int32 => parse_integer(raw, i32::MIN.into(), i32::MAX.into(), "int32"),
+            FieldType::Uint32 => parse_integer(raw, 0, u32::MAX.into(), "uint32"),
+            FieldType::Int64 => parse_int64(raw, i64::MIN.into(), i64::MAX.into(), "int64")
+                .map(|bits| Value::Int64(bits as i64)),
+            FieldType::Uint64 => parse_int64(raw, 0, u64::MAX.into(), "uint64").map(Value::Uint64),
+            FieldType::Int16 => parse_integer(raw, i16::MIN.into(), i16::MAX.into(), "int16"),
+            FieldType::Uint16 => parse_integer(raw, 0, u16::MAX.into(), "uint16"),
+            FieldType::Byte => parse_integer(raw, 0, u8::MAX.into(), "byte"),
+            FieldType::SByte => parse_integer(raw, i8::MIN.into(), i8::MAX.into(), "sbyte"),
+            // Colors are packed 32-bit integers, rows and custom types hold row keys
+            FieldType::Color => parse_integer(raw, 0, u32::MAX.into(), "Color"),
+            FieldType::Row | FieldType::Custom(_) => {
+                parse_integer(raw, 0, u32::MAX.into(), "row key")
+            }
+            FieldType::Float => parse_float(raw),
+            FieldType::Bool | FieldType::Bit(_) => parse_bool(raw),
+            // Composite types are read column by column through their element types
+            FieldType::Array(..) | FieldType::Struct(_) => {
+                Err("composite fields span multiple columns".to_string())
+            }
+        }
+    }
+}
+
+fn parse_integer(raw: &str, min: i64, max: i64, type_name: &str) -> Result<Value, String> {
+    let value = raw
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| format!("not a valid {} value", type_name))?;
+
+    if value < min || value > max {
+        return Err(format!(
+            "{} is out of range for {} ({}..={})",
+            value, type_name, min, max
+        ));
+    }
+
+    Ok(Value::Int(value))
+}
+
+/// Parse a 64-bit column into its raw bits.
+///
+/// Accepts either a plain integer within `min..=max` or the packed quad form `"a, b, c, d"`,
+/// where each part is a 16-bit value and the first part is the least significant.
+fn parse_int64(raw: &str, min: i128, max: i128, type_name: &str) -> Result<u64, String> {
+    let trimmed = raw.trim();
+
+    if !trimmed.contains(',') {
+        let value = trimmed
+            .parse::<i128>()
+            .map_err(|_| format!("not a valid {} value", type_name))?;
+
+        if value < min || value > max {
+            return Err(format!(
+                "{} is out of range for {} ({}..={})",
+                value, type_name, min, max
+            ));
+        }
+
+        // Two's complement bits, so negative int64 values round-trip through `as i64`
+        return Ok(value as u64);
+    }
+
+    let parts = trimmed
+        .split(',')
+        .map(|part| part.trim().parse::<u16>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| "quad parts must be 16-bit unsigned integers".to_string())?;
+
+    match parts.as_slice() {
+        [a, b, c, d] => Ok(quad_to_bits([*a, *b, *c, *d])),
+        _ => Err(format!("expected 4 quad parts, found {}", parts.len())),
+    }
+}
+
+/// Split 64-bit raw bits into four 16-bit parts, least significant first
+pub fn bits_to_quad(bits: u64) -> [u16; 4] {
+    [
+        bits as u16,
+        (bits >> 16) as u16,
+        (bits >> 32) as u16,
+        (bits >> 48) as u16,
+    ]
+}
+
+/// Pack four 16-bit parts, least significant first, into 64-bit raw bits
+pub fn quad_to_bits(quad: [u16; 4]) -> u64 {
+    quad.iter()
+        .rev()
+        .fold(0u64, |bits, part| (bits << 16) | u64::from(*part))
+}
+
+fn int64_to_json(bits: u64, decimal: String, int64: Int64Representation) -> JsonValue {
+    match int64 {
+        Int64Representation::BigInt | Int64Representation::String => JsonValue::String(decimal),
+        Int64Representation::Number => decimal
+            .parse::<serde_json::Number>()
+            .map(JsonValue::Number)
+            .unwrap_or(JsonValue::String(decimal)),
+        Int64Representation::Quad => JsonValue::from(bits_to_quad(bits).to_vec()),
+    }
+}
+
+fn parse_float(raw: &str) -> Result<Value, String> {
+    match raw.trim().parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(Value::Float(value)),
+        Ok(_) => Err("float value must be finite".to_string()),
+        Err(_) => Err("not a valid float value".to_string()),
+    }
+}
+
+fn parse_bool(raw: &str) -> Result<Value, String> {
+    match raw.trim() {
+        value if value.eq_ignore_ascii_case("true") => Ok(Value::Bool(true)),
+        value if value.eq_ignore_ascii_case("false") => Ok(Value::Bool(false)),
+        _ => Err("expected True or False".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_integer_types() {
+        assert_eq!(FieldType::Int32.parse_value("-42"), Ok(Value::Int(-42)));
+        assert_eq!(
+            FieldType::Uint32.parse_value("999999999"),
+            Ok(Value::Int(999999999))
+        );
+        assert_eq!(
+            FieldType::Int16.parse_value("-32768"),
+            Ok(Value::Int(-32768))
+        );
+        assert_eq!(
+            FieldType::Uint16.parse_value("65535"),
+            Ok(Value::Int(65535))
+        );
+        assert_eq!(FieldType::Byte.parse_value("255"), Ok(Value::Int(255)));
+        assert_eq!(FieldType::SByte.parse_value("-128"), Ok(Value::Int(-128)));
+    }
+
+    #[test]
+    fn test_parse_out_of_range_integers() {
+        assert_eq!(
+            FieldType::Byte.parse_value("300"),
+            Err("300 is out of range for byte (0..=255)".to_string())
+        );
+        assert_eq!(
+            FieldType::SByte.parse_value("-200"),
+            Err("-200 is out of range for sbyte (-128..=127)".to_string())
+        );
+        assert!(FieldType::Uint16.parse_value("-1").is_err());
+        assert!(FieldType::Uint32.parse_value("4294967296").is_err());
+        assert!(FieldType::Int32.parse_value("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_bool_and_bit() {
+        assert_eq!(FieldType::Bool.parse_value("True"), Ok(Value::Bool(true)));
+        assert_eq!(FieldType::Bool.parse_value("false"), Ok(Value::Bool(false)));
+        assert_eq!(
+            FieldType::Bit(0x80).parse_value("False"),
+            Ok(Value::Bool(false))
+        );
+        assert_eq!(
+            FieldType::Bit(1).parse_value("1"),
+            Err("expected True or False".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_float() {
+        assert_eq!(FieldType::Float.parse_value("1.5"), Ok(Value::Float(1.5)));
+        assert!(FieldType::Float.parse_value("1.5.2").is_err());
+        assert!(FieldType::Float.parse_value("NaN").is_err());
+    }
+
+    #[test]
+    fn test_parse_special_types() {
+        assert_eq!(
+            FieldType::Image.parse_value("021001"),
+            Ok(Value::String("021001".to_string()))
+        );
+        assert!(FieldType::Image.parse_value("icon.png").is_err());
+        assert_eq!(
+            FieldType::Color.parse_value("16711680"),
+            Ok(Value::Int(16711680))
+        );
+        assert_eq!(FieldType::Row.parse_value("0"), Ok(Value::Int(0)));
+        assert_eq!(
+            FieldType::Key.parse_value("active"),
+            Ok(Value::String("active".to_string()))
+        );
+        assert_eq!(
+            FieldType::Custom("ItemCategory".to_string()).parse_value("3"),
+            Ok(Value::Int(3))
+        );
+    }
+
+    #[test]
+    fn test_parse_int64() {
+        assert_eq!(FieldType::Int64.parse_value("-1"), Ok(Value::Int64(-1)));
+        assert_eq!(
+            FieldType::Uint64.parse_value("18446744073709551615"),
+            Ok(Value::Uint64(u64::MAX))
+        );
+        assert_eq!(
+            FieldType::Int64.parse_value("0, 0, 0, 0"),
+            Ok(Value::Int64(0))
+        );
+        assert_eq!(
+            FieldType::Int64.parse_value("6016, 1, 0, 0"),
+            Ok(Value::Int64(6016 + (1 << 16)))
+        );
+        assert!(FieldType::Int64.parse_value("1, 2, 3").is_err());
+        assert!(FieldType::Int64.parse_value("70000, 0, 0, 0").is_err());
+        assert!(FieldType::Uint64.parse_value("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_out_of_range_int64() {
+        assert_eq!(
+            FieldType::Uint64.parse_value("-1"),
+            Err("-1 is out of range for uint64 (0..=18446744073709551615)".to_string())
+        );
+        assert_eq!(
+            FieldType::Int64.parse_value("18446744073709551615"),
+            Err("18446744073709551615 is out of range for int64 \
+                 (-9223372036854775808..=9223372036854775807)"
+                .to_string())
+        );
+        assert_eq!(
+            FieldType::Int64.parse_value("-9223372036854775808"),
+            Ok(Value::Int64(i64::MIN))
+        );
+        assert_eq!(
+            FieldType::Int64.parse_value("9223372036854775807"),
+            Ok(Value::Int64(i64::MAX))
+        );
+        assert!(
+            FieldType::Uint64
+                .parse_value("18446744073709551616")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_quad_round_trip() {
+        let quad = [6016, 1, 2, 65535];
+        assert_eq!(bits_to_quad(quad_to_bits(quad)), quad);
+        assert_eq!(quad_to_bits([1, 0, 0, 0]), 1);
+        assert_eq!(quad_to_bits([0, 0, 0, 1]), 1 << 48);
+    }
+
+    #[test]
+    fn test_value_to_json() {
+        let int64 = Int64Representation::default();
+        assert_eq!(Value::Int(3).to_json(int64), serde_json::json!(3));
+        assert_eq!(Value::Float(1.5).to_json(int64), serde_json::json!(1.5));
+        assert_eq!(Value::Bool(true).to_json(int64), serde_json::json!(true));
+        assert_eq!(
+            Value::String("Sword".to_string()).to_json(int64),
+            serde_json::json!("Sword")
+        );
+    }
+
+    #[test]
+    fn test_int64_to_json_representations() {
+        let value = Value::Int64(6016 + (1 << 16));
+        assert_eq!(
+            value.to_json(Int64Representation::BigInt),
+            serde_json::json!("71552")
+        );
+        assert_eq!(
+            value.to_json(Int64Representation::String),
+            serde_json::json!("71552")
+        );
+        assert_eq!(
+            value.to_json(Int64Representation::Number),
+            serde_json::json!(71552)
+        );
+        assert_eq!(
+            value.to_json(Int64Representation::Quad),
+            serde_json::json!([6016, 1, 0, 0])
+        );
+        assert_eq!(
+            Value::Uint64(u64::MAX).to_json(Int64Representation::Number),
+            serde_json::json!(u64::MAX)
+        );
+    }
+}
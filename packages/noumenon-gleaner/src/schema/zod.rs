@@ -0,0 +1,277 @@
+use super::generator::SchemaGenerator;
+use super::types::{FieldType, Int64Representation, Schema, SchemaMap};
+
+pub struct ZodGenerator {
+    int64_representation: Int64Representation,
+}
+
+impl ZodGenerator {
+    pub fn new() -> Self {
+        Self {
+            int64_representation: Int64Representation::default(),
+        }
+    }
+
+    /// Set how 64-bit integer values are parsed, matching the extracted data
+    pub fn with_int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
+
+    /// Generate Zod object schemas for all schemas
+    pub fn generate_zod_schemas(&self, schemas: &SchemaMap) -> String {
+        let mut content = String::new();
+
+        // Add header comment
+        content.push_str("// Generated Zod schemas from CSV schemas\n");
+        content.push_str("// This file is auto-generated. Do not edit manually.\n\n");
+        content.push_str("import { z } from \"zod\";\n\n");
+
+        content.push_str("/** Integer within the range of its CSV column type */\n");
+        content.push_str("const int = (minimum: number, maximum: number) =>\n");
+        content.push_str("  z.number().int().min(minimum).max(maximum);\n\n");
+
+        // Add special type definitions
+        content.push_str(&self.generate_special_schemas());
+        content.push('\n');
+
+        // Sort schemas by name for consistent output
+        let mut sorted_schemas: Vec<&Schema> = schemas.values().collect();
+        sorted_schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Branded IDs come first so every object can reference them
+        content.push_str("// Branded row key schemas referencing each sheet\n");
+        for schema in &sorted_schemas {
+            let id_name = id_schema_name(&schema.name);
+            content.push_str(&format!(
+                "export const {} = int(0, {}).brand<\"{}\">();\n",
+                id_name,
+                u32::MAX,
+                schema.name
+            ));
+            content.push_str(&format!(
+                "export type {} = z.infer<typeof {}>;\n",
+                id_name, id_name
+            ));
+        }
+        content.push('\n');
+
+        for schema in sorted_schemas {
+            content.push_str(&self.generate_object(schema));
+            content.push('\n');
+        }
+
+        content
+    }
+
+    fn generate_special_schemas(&self) -> String {
+        let mut schemas = String::new();
+
+        schemas.push_str("// Special type definitions for game data\n");
+        schemas.push_str("/** Image file path identifier */\n");
+        schemas.push_str("export const ImagePath = z.string().regex(/^[0-9]+$/);\n\n");
+
+        schemas.push_str("/** Row reference identifier */\n");
+        schemas.push_str(&format!("export const RowId = int(0, {});\n\n", u32::MAX));
+
+        schemas.push_str("/** Key identifier string */\n");
+        schemas.push_str("export const KeyString = z.string();\n\n");
+
+        schemas.push_str("/** Color code identifier */\n");
+        schemas.push_str(&format!("export const ColorCode = int(0, {});\n", u32::MAX));
+
+        schemas
+    }
+
+    fn generate_object(&self, schema: &Schema) -> String {
+        let mut definition = String::new();
+
+        definition.push_str(&format!("export const {} = z.object({{\n", schema.name));
+        for field in &schema.fields {
+            definition.push_str(&format!(
+                "  {}: {},\n",
+                field.name,
+                self.field_type_to_zod(&field.field_type)
+            ));
+        }
+        definition.push_str("});\n");
+        definition.push_str(&format!(
+            "export type {} = z.infer<typeof {}>;\n",
+            schema.name, schema.name
+        ));
+
+        definition
+    }
+
+    /// Convert FieldType to a Zod schema expression
+    fn field_type_to_zod(&self, field_type: &FieldType) -> String {
+        match field_type {
+            FieldType::String => "z.string()".to_string(),
+            FieldType::Int64 => self.int64_to_zod(i64::MIN.into(), i64::MAX.into()),
+            FieldType::Uint64 => self.int64_to_zod(0, u64::MAX.into()),
+            FieldType::Float => "z.number()".to_string(),
+            FieldType::Bool | FieldType::Bit(_) => "z.boolean()".to_string(),
+            FieldType::Image => "ImagePath".to_string(),
+            FieldType::Row => "RowId".to_string(),
+            FieldType::Key => "KeyString".to_string(),
+            FieldType::Color => "ColorCode".to_string(),
+            FieldType::Custom(type_name) => id_schema_name(type_name),
+            FieldType::Array(element_type, length) => format!(
+                "z.array({}).length({})",
+                self.field_type_to_zod(element_type),
+                length
+            ),
+            FieldType::Struct(members) => {
+                let members: Vec<String> = members
+                    .iter()
+                    .map(|(name, member_type)| {
+                        format!("{}: {}", name, self.field_type_to_zod(member_type))
+                    })
+                    .collect();
+                format!("z.object({{ {} }})", members.join(", "))
+            }
+            _ => match field_type.integer_bounds() {
+                Some((minimum, maximum)) => format!("int({}, {})", minimum, maximum),
+                None => "z.unknown()".to_string(),
+            },
+        }
+    }
+
+    fn int64_to_zod(&self, minimum: i128, maximum: i128) -> String {
+        let pattern = if minimum < 0 {
+            "/^-?[0-9]+$/"
+        } else {
+            "/^[0-9]+$/"
+        };
+
+        match self.int64_representation {
+            // Decimal strings in the data parse to bigint
+            Int64Representation::BigInt => format!(
+                "z.string().regex({}).transform((value) => BigInt(value)).pipe(z.bigint().min({}n).max({}n))",
+                pattern, minimum, maximum
+            ),
+            Int64Representation::String => format!("z.string().regex({})", pattern),
+            Int64Representation::Number => "z.number().int()".to_string(),
+            Int64Representation::Quad => {
+                let part = format!("int(0, {})", u16::MAX);
+                format!("z.tuple([{}])", vec![part; 4].join(", "))
+            }
+        }
+    }
+}
+
+impl SchemaGenerator for ZodGenerator {
+    fn name(&self) -> &'static str {
+        "zod"
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "zod.ts"
+    }
+
+    fn supports_inlined_data(&self) -> bool {
+        false
+    }
+
+    fn generate(&self, schemas: &SchemaMap) -> String {
+        self.generate_zod_schemas(schemas)
+    }
+}
+
+impl Default for ZodGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn id_schema_name(schema_name: &str) -> String {
+    format!("{}Id", schema_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::types::Field;
+
+    fn create_test_schemas() -> SchemaMap {
+        let fields = vec![
+            ("id", FieldType::Int32),
+            ("name", FieldType::String),
+            ("level", FieldType::Uint16),
+            ("isUnique", FieldType::Bool),
+            ("color", FieldType::Color),
+            ("category", FieldType::Custom("ItemCategory".to_string())),
+            (
+                "baseParam",
+                FieldType::Array(
+                    Box::new(FieldType::Struct(vec![
+                        (
+                            "param".to_string(),
+                            FieldType::Custom("BaseParam".to_string()),
+                        ),
+                        ("value".to_string(), FieldType::SByte),
+                    ])),
+                    3,
+                ),
+            ),
+            ("model", FieldType::Int64),
+        ];
+
+        SchemaMap::from([(
+            "Item".to_string(),
+            Schema {
+                name: "Item".to_string(),
+                fields: fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(column, (name, field_type))| Field {
+                        name: name.to_string(),
+                        field_type,
+                        columns: vec![column],
+                    })
+                    .collect(),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_generate_object() {
+        let generator = ZodGenerator::new();
+        let schemas = create_test_schemas();
+        let zod = generator.generate_zod_schemas(&schemas);
+
+        assert!(zod.contains("import { z } from \"zod\";\n"));
+        assert!(zod.contains(
+            "export const ItemId = int(0, 4294967295).brand<\"Item\">();\nexport type ItemId = z.infer<typeof ItemId>;\n"
+        ));
+        assert!(zod.contains(
+            r#"export const Item = z.object({
+  id: int(-2147483648, 2147483647),
+  name: z.string(),
+  level: int(0, 65535),
+  isUnique: z.boolean(),
+  color: ColorCode,
+  category: ItemCategoryId,
+  baseParam: z.array(z.object({ param: BaseParamId, value: int(-128, 127) })).length(3),
+  model: z.string().regex(/^-?[0-9]+$/).transform((value) => BigInt(value)).pipe(z.bigint().min(-9223372036854775808n).max(9223372036854775807n)),
+});
+export type Item = z.infer<typeof Item>;
+"#
+        ));
+    }
+
+    #[test]
+    fn test_int64_follows_representation() {
+        let generator = ZodGenerator::new().with_int64_representation(Int64Representation::String);
+        assert_eq!(
+            generator.field_type_to_zod(&FieldType::Uint64),
+            "z.string().regex(/^[0-9]+$/)"
+        );
+
+        let generator = ZodGenerator::new().with_int64_representation(Int64Representation::Number);
+        assert_eq!(
+            generator.field_type_to_zod(&FieldType::Int64),
+            "z.number().int()"
+        );
+    }
+}